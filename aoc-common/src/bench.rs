@@ -0,0 +1,55 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Sorted timing samples of one measured step.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    samples: Vec<Duration>,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort();
+        Stats { samples }
+    }
+
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let n = self.samples.len();
+        if n.is_multiple_of(2) {
+            (self.samples[n / 2 - 1] + self.samples[n / 2]) / 2
+        } else {
+            self.samples[n / 2]
+        }
+    }
+
+    /// 95th percentile, nearest-rank method.
+    pub fn p95(&self) -> Duration {
+        let rank = (self.samples.len() * 95).div_ceil(100);
+        self.samples[rank.max(1) - 1]
+    }
+}
+
+/// Run `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let samples = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::new(samples)
+}
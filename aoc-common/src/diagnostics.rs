@@ -0,0 +1,69 @@
+use crate::error::{Error, ParseError, Result};
+
+/// Collects the malformed lines a parser skips over.
+///
+/// In strict mode the first reported problem becomes an error instead, so a
+/// parser can simply `?` the result of [`Diagnostics::report`].
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    file: Option<String>,
+    strict: bool,
+    warnings: Vec<ParseError>,
+}
+
+impl Diagnostics {
+    pub fn new(strict: bool) -> Self {
+        Diagnostics { strict, ..Default::default() }
+    }
+
+    /// Name the input in every reported location.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Record a malformed piece of input that the parser is able to skip.
+    pub fn report(&mut self, mut error: ParseError) -> Result<()> {
+        if error.file.is_none() {
+            error.file = self.file.clone();
+        }
+        if self.strict {
+            return Err(Error::Parse(error));
+        }
+        self.warnings.push(error);
+        Ok(())
+    }
+
+    /// Attach the input name to a parse error the parser cannot recover from.
+    pub fn locate(&self, error: Error) -> Error {
+        match error {
+            Error::Parse(mut err) if err.file.is_none() => {
+                err.file = self.file.clone();
+                Error::Parse(err)
+            }
+            other => other,
+        }
+    }
+
+    pub fn warnings(&self) -> &[ParseError] {
+        &self.warnings
+    }
+
+    pub fn into_warnings(self) -> Vec<ParseError> {
+        self.warnings
+    }
+
+    pub fn print_warnings(&self) {
+        for warning in &self.warnings {
+            eprintln!("warning: {}", warning);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+/// One of the four cardinal directions on a grid.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// Row/column deltas for all eight neighbours, clockwise starting east.
+pub const ALL_DIRECTIONS: [(i32, i32); 8] = [
+    (0, 1), (1, 1), (1, 0), (1, -1),
+    (0, -1), (-1, -1), (-1, 0), (-1, 1),
+];
+
+impl Direction {
+    pub fn rotate_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Row/column delta of a single step in this direction.
+    pub fn get_delta(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Error type shared by all day crates.
+pub enum Error {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A named input file could not be read.
+    Read { path: PathBuf, source: io::Error },
+    /// A line of the input could not be parsed.
+    Parse(ParseError),
+    /// The input was readable but does not describe a valid puzzle.
+    InvalidInput(String),
+    /// A result does not fit in the type it is computed in.
+    Overflow(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A malformed piece of input and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Input file name, filled in by [`Diagnostics`](crate::Diagnostics).
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of `text` within the line.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for `token` on the 0-based line `index`. `token` should be a
+    /// slice of `line` so that its column can be worked out; otherwise the
+    /// column points at the start of the line.
+    pub fn new(index: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: index + 1,
+            column: column(line, token),
+            text: token.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// 1-based column at which the subslice `token` starts within `line`.
+pub fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}: {}: '{}'", self.line, self.column, self.message, self.text)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::Read { path, source } => write!(f, "Cannot read {}: {}", path.display(), source),
+            Error::Parse(err) => write!(f, "Parse error: {}", err),
+            Error::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            Error::Overflow(msg) => write!(f, "Overflow: {}", msg),
+        }
+    }
+}
+
+// `main` prints the `Debug` form of a returned error, so keep it readable.
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Read { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
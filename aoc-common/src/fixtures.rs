@@ -0,0 +1,32 @@
+//! Directories of puzzle inputs, each `name.txt` optionally paired with a
+//! `name.answer.txt` holding the expected part 1 and part 2 answers.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+const ANSWER_SUFFIX: &str = ".answer.txt";
+
+/// Puzzle inputs in `dir`: every `*.txt` file except the `*.answer.txt`
+/// siblings, sorted by name.
+pub fn collect_inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|source| Error::Read { path: dir.to_path_buf(), source })?;
+
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if path.is_file() && name.ends_with(".txt") && !name.ends_with(ANSWER_SUFFIX) {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// `inputs/alice.txt` -> `inputs/alice.answer.txt`
+pub fn answer_path(input: &Path) -> PathBuf {
+    let stem = input.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    input.with_file_name(format!("{}{}", stem, ANSWER_SUFFIX))
+}
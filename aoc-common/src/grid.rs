@@ -0,0 +1,55 @@
+use crate::error::{ParseError, Result};
+
+/// Rectangular character grid, indexed as `cells[row][col]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    pub cells: Vec<Vec<char>>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Grid {
+    pub fn parse(content: &str) -> Result<Self> {
+        let lines: Vec<&str> = content.lines().map(str::trim).collect();
+        let cells: Vec<Vec<char>> = lines
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+
+        let rows = cells.len();
+        let cols = if rows > 0 { cells[0].len() } else { 0 };
+
+        if let Some(index) = cells.iter().position(|row| row.len() != cols) {
+            let line = lines[index];
+            let message = format!("Inconsistent row length {} (expected {})", cells[index].len(), cols);
+            return Err(ParseError::new(index, line, line, message).into());
+        }
+
+        Ok(Grid { cells, rows, cols })
+    }
+
+    pub fn is_valid_position(&self, row: i32, col: i32) -> bool {
+        row >= 0 && row < self.rows as i32 && col >= 0 && col < self.cols as i32
+    }
+
+    /// Cell at a signed position, or `None` when it lies outside the grid.
+    pub fn get(&self, row: i32, col: i32) -> Option<char> {
+        if self.is_valid_position(row, col) {
+            Some(self.cells[row as usize][col as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Position of the first cell equal to `target`, scanning row by row.
+    pub fn find(&self, target: char) -> Option<(usize, usize)> {
+        for (row, row_chars) in self.cells.iter().enumerate() {
+            for (col, &ch) in row_chars.iter().enumerate() {
+                if ch == target {
+                    return Some((row, col));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, Result};
+
+/// Default location of the puzzle input, relative to a day crate.
+pub const DEFAULT_INPUT: &str = "data/input.txt";
+
+/// Default location of the accepted answers, relative to a day crate.
+pub const DEFAULT_ANSWER: &str = "data/answer.txt";
+
+/// Environment variable that overrides the default input path.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Input options shared by every day binary.
+#[derive(Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Puzzle input file, or - to read from stdin
+    #[arg(env = INPUT_ENV, default_value = DEFAULT_INPUT)]
+    pub input: InputSource,
+    /// Fail on the first malformed line instead of warning and skipping it
+    #[arg(long)]
+    pub strict: bool,
+}
+
+impl InputArgs {
+    pub fn read_to_string(&self) -> Result<String> {
+        self.input.read_to_string()
+    }
+
+    /// Diagnostics for parsing this input, honouring `--strict`.
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::new(self.strict).with_file(self.input.to_string())
+    }
+}
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read_to_string(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+            InputSource::File(path) => read_to_string(path),
+        }
+    }
+
+    /// Buffered reader over the input, for days that stream it line by line.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|source| read_error(path, source))?;
+                Ok(Box::new(io::BufReader::new(file)))
+            }
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => f.write_str("<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn read_error(path: &Path, source: io::Error) -> Error {
+    Error::Read { path: path.to_path_buf(), source }
+}
+
+pub fn read_to_string<P>(filename: P) -> Result<String>
+where P: AsRef<Path>, {
+    fs::read_to_string(&filename).map_err(|source| read_error(filename.as_ref(), source))
+}
+
+/// Accepted answers, one per line: part 1 first, then part 2.
+pub fn read_answers<P>(filename: P) -> Result<Vec<String>>
+where P: AsRef<Path>, {
+    Ok(read_to_string(filename)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
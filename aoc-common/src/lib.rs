@@ -0,0 +1,22 @@
+//! Shared building blocks for the Advent of Code 2024 solutions.
+//!
+//! Every day crate depends on this library for input loading, grid parsing,
+//! direction math, the common error type and the [`Solution`] trait every
+//! day implements.
+
+pub mod bench;
+pub mod diagnostics;
+pub mod direction;
+pub mod error;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod solution;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use diagnostics::Diagnostics;
+pub use direction::Direction;
+pub use error::{Error, ParseError, Result};
+pub use grid::Grid;
+pub use solution::{Answer, Part, Puzzle, Solution};
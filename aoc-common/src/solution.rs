@@ -0,0 +1,106 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::diagnostics::Diagnostics;
+use crate::error::{Error, Result};
+
+/// Answer to one part of a puzzle, kept as text so every day can report
+/// whatever numeric type it computes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            fn from(value: $ty) -> Self {
+                Answer(value.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer_from!(i32, i64, u32, u64, usize, String, &str);
+
+/// Which half of a puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A single day's puzzle: parse the input once, then answer both parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input: 'static;
+
+    /// Parse the puzzle input, reporting skippable malformed lines to `diag`.
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+/// Object-safe view of a [`Solution`], so a runner can hold every day in one
+/// list. Implemented for every `Solution` type.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn parse_input(&self, input: &str, diag: &mut Diagnostics) -> Result<Box<dyn Any>>;
+    fn solve_part(&self, input: &dyn Any, part: Part) -> Result<Answer>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse_input(&self, input: &str, diag: &mut Diagnostics) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input, diag)?))
+    }
+
+    fn solve_part(&self, input: &dyn Any, part: Part) -> Result<Answer> {
+        let input = input.downcast_ref::<S::Input>().ok_or_else(|| {
+            Error::InvalidInput(format!("Input was not parsed by day {}", S::DAY))
+        })?;
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Helpers for the days' integration tests and benches. Only built with the
+//! `testing` feature, which the day crates enable for their dev builds.
+
+use std::path::Path;
+
+use crate::diagnostics::Diagnostics;
+use crate::fixtures::{answer_path, collect_inputs};
+use crate::input;
+use crate::solution::Solution;
+
+/// Assert that `S` parses every fixture in `dir` strictly and reproduces its
+/// stored answers. Meant to be called from a day's integration tests.
+pub fn check_examples<S: Solution>(dir: &str) {
+    let inputs = collect_inputs(Path::new(dir)).unwrap();
+    assert!(!inputs.is_empty(), "no example inputs in {}", dir);
+
+    for path in inputs {
+        let content = input::read_to_string(&path).unwrap();
+        let expected = input::read_answers(answer_path(&path)).unwrap();
+        assert_eq!(expected.len(), 2, "{} needs one answer per part", answer_path(&path).display());
+        let mut diag = Diagnostics::new(true).with_file(path.display().to_string());
+        let parsed = S::parse(&content, &mut diag).unwrap();
+
+        let actual = [S::part1(&parsed).unwrap(), S::part2(&parsed).unwrap()];
+        for (part, (expected, actual)) in expected.iter().zip(&actual).enumerate() {
+            assert_eq!(
+                expected,
+                actual.as_str(),
+                "{} part {}",
+                path.display(),
+                part + 1
+            );
+        }
+    }
+}
+
+/// Seeded xorshift64 generator, for reproducible benchmark and test data.
+///
+/// ```
+/// use aoc_common::testing::Rng;
+///
+/// let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+/// let dice: Vec<u64> = (0..5).map(|_| a.below(6)).collect();
+/// assert!(dice.iter().all(|&die| die < 6));
+/// assert_eq!(dice, (0..5).map(|_| b.below(6)).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A generator starting from `seed`, which must not be zero.
+    pub fn new(seed: u64) -> Rng {
+        assert_ne!(seed, 0, "xorshift64 needs a non-zero seed");
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Next value reduced to `0..bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::fixtures::{answer_path, collect_inputs};
+use aoc_common::{input, Diagnostics, Part, Puzzle, Result};
+
+use crate::runner::{self, DayResult};
+
+/// One input file of a batch run.
+pub struct BatchEntry {
+    pub path: PathBuf,
+    pub outcome: Result<DayResult>,
+    /// Answers from the sibling answer file, or why it could not be read.
+    pub expected: Option<Result<Vec<String>>>,
+}
+
+pub enum MatchStatus {
+    /// No sibling answer file to compare against.
+    Unknown,
+    Pass,
+    Fail,
+    Error,
+    /// The sibling answer file could not be read.
+    AnswerError,
+}
+
+impl BatchEntry {
+    pub fn status(&self) -> MatchStatus {
+        let result = match &self.outcome {
+            Ok(result) => result,
+            Err(_) => return MatchStatus::Error,
+        };
+        let expected = match &self.expected {
+            Some(Ok(expected)) => expected,
+            Some(Err(_)) => return MatchStatus::AnswerError,
+            None => return MatchStatus::Unknown,
+        };
+
+        let all_match = result.parts.iter().all(|part| {
+            expected.get(part.part.number() as usize - 1).map(String::as_str)
+                == Some(part.answer.as_str())
+        });
+        if all_match { MatchStatus::Pass } else { MatchStatus::Fail }
+    }
+}
+
+/// Solve `puzzle` for every input in `dir`. A failing input or unreadable
+/// answer file is recorded in its entry rather than aborting the rest of the
+/// batch.
+pub fn run(puzzle: &dyn Puzzle, dir: &Path, strict: bool, parts: &[Part]) -> Result<Vec<BatchEntry>> {
+    let mut entries = Vec::new();
+    for path in collect_inputs(dir)? {
+        let diag = Diagnostics::new(strict).with_file(path.display().to_string());
+        let outcome = input::read_to_string(&path)
+            .and_then(|content| runner::run(puzzle, &content, diag, parts));
+        let answers = answer_path(&path);
+        let expected = answers.is_file().then(|| input::read_answers(&answers));
+        entries.push(BatchEntry { path, outcome, expected });
+    }
+    Ok(entries)
+}
+
+pub fn print_entries(entries: &[BatchEntry]) {
+    let rows: Vec<[String; 5]> = entries
+        .iter()
+        .map(|entry| {
+            let name = entry.path.file_name().map_or(String::new(), |n| n.to_string_lossy().into_owned());
+            let status = match (entry.status(), &entry.expected) {
+                (MatchStatus::AnswerError, Some(Err(err))) => format!("answer error: {}", err),
+                (MatchStatus::Unknown, _) => "-".to_string(),
+                (MatchStatus::Pass, _) => "PASS".to_string(),
+                (MatchStatus::Fail, _) => "FAIL".to_string(),
+                (MatchStatus::Error | MatchStatus::AnswerError, _) => "ERROR".to_string(),
+            };
+            match &entry.outcome {
+                Ok(result) => {
+                    let answer = |part| result.answer(part).map_or("-".to_string(), |a| a.to_string());
+                    [name, answer(Part::One), answer(Part::Two), format!("{:.2?}", result.total_time()), status]
+                }
+                Err(err) => [name, err.to_string(), String::new(), String::new(), status],
+            }
+        })
+        .collect();
+    runner::print_rows(&["Input", "Part 1", "Part 2", "Time", "Match"], &rows);
+}
@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc_common::bench::{self, Stats};
+use aoc_common::{Diagnostics, Error, Part, Puzzle, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{self, nanos};
+
+/// Timings of one step (`parse`, `part1` or `part2`) of one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepTiming {
+    pub day: u8,
+    pub step: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+/// One `aoc bench` invocation as stored in the history file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub timings: Vec<StepTiming>,
+}
+
+impl BenchRun {
+    pub fn find(&self, day: u8, step: &str) -> Option<&StepTiming> {
+        self.timings.iter().find(|t| t.day == day && t.step == step)
+    }
+}
+
+fn timing(day: u8, step: &str, stats: &Stats) -> StepTiming {
+    StepTiming {
+        day,
+        step: step.to_string(),
+        min_ns: nanos(stats.min()),
+        median_ns: nanos(stats.median()),
+        p95_ns: nanos(stats.p95()),
+    }
+}
+
+/// Time parsing and both parts of `puzzle` separately. Each step is run once
+/// up front so that a failing input is reported instead of timed.
+pub fn bench_day(puzzle: &dyn Puzzle, input: &str, warmup: usize, iterations: usize) -> Result<Vec<StepTiming>> {
+    let parsed = puzzle.parse_input(input, &mut Diagnostics::default())?;
+    for part in Part::BOTH {
+        puzzle.solve_part(parsed.as_ref(), part)?;
+    }
+
+    let mut timings = vec![timing(
+        puzzle.day(),
+        "parse",
+        &bench::measure(warmup, iterations, || puzzle.parse_input(input, &mut Diagnostics::default())),
+    )];
+    for part in Part::BOTH {
+        let stats = bench::measure(warmup, iterations, || puzzle.solve_part(parsed.as_ref(), part));
+        timings.push(timing(puzzle.day(), &format!("part{}", part.number()), &stats));
+    }
+    Ok(timings)
+}
+
+/// Short hash of the checked-out commit, if this is a git work tree.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn new_run(timings: Vec<StepTiming>, warmup: usize, iterations: usize) -> BenchRun {
+    BenchRun {
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+        commit: current_commit(),
+        iterations,
+        warmup,
+        timings,
+    }
+}
+
+/// Previous runs, oldest first. A missing file is an empty history.
+pub fn load_history(path: &Path) -> Result<Vec<BenchRun>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = aoc_common::input::read_to_string(path)?;
+    serde_json::from_str(&content)
+        .map_err(|err| Error::InvalidInput(format!("{}: {}", path.display(), err)))
+}
+
+pub fn save_history(path: &Path, history: &[BenchRun]) -> Result<()> {
+    let json = serde_json::to_string_pretty(history)
+        .map_err(|err| Error::InvalidInput(err.to_string()))?;
+    fs::write(path, json + "\n")?;
+    Ok(())
+}
+
+/// Change of the median relative to `previous`, in percent.
+pub fn change_percent(current: &StepTiming, previous: &StepTiming) -> f64 {
+    if previous.median_ns == 0 {
+        return 0.0;
+    }
+    (current.median_ns as f64 / previous.median_ns as f64 - 1.0) * 100.0
+}
+
+/// Most recent timing of the same step in `history`.
+pub fn previous<'a>(history: &'a [BenchRun], timing: &StepTiming) -> Option<&'a StepTiming> {
+    history.iter().rev().find_map(|run| run.find(timing.day, &timing.step))
+}
+
+/// Print `run` next to the latest earlier timing of each step and return how
+/// many steps got slower by more than `threshold` percent.
+pub fn report(run: &BenchRun, history: &[BenchRun], threshold: f64) -> usize {
+    let mut regressions = 0;
+    let rows: Vec<[String; 8]> = run
+        .timings
+        .iter()
+        .map(|t| {
+            let prev = previous(history, t);
+            let (prev_median, change, flag) = match prev {
+                Some(prev) => {
+                    let change = change_percent(t, prev);
+                    let flag = if change > threshold {
+                        regressions += 1;
+                        "REGRESSION"
+                    } else {
+                        ""
+                    };
+                    (format_ns(prev.median_ns), format!("{:+.1}%", change), flag)
+                }
+                None => ("-".to_string(), "-".to_string(), ""),
+            };
+            [
+                t.day.to_string(),
+                t.step.clone(),
+                format_ns(t.min_ns),
+                format_ns(t.median_ns),
+                format_ns(t.p95_ns),
+                prev_median,
+                change,
+                flag.to_string(),
+            ]
+        })
+        .collect();
+    runner::print_rows(&["Day", "Step", "Min", "Median", "P95", "Prev median", "Change", "Status"], &rows);
+    regressions
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
@@ -0,0 +1,9 @@
+//! Runner plumbing shared by the `aoc` binary and the workspace regression
+//! tests.
+
+pub mod batch;
+pub mod bench;
+pub mod output;
+pub mod registry;
+pub mod runner;
+pub mod verify;
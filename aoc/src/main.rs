@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::batch::{self, MatchStatus};
+use aoc::output::{self, OutputFormat};
+use aoc::{bench, registry, runner, verify};
+use aoc_common::input::{self, InputSource};
+use aoc_common::{Diagnostics, Error, Part, Puzzle, Result};
+use clap::{Args, Parser, Subcommand};
+
+/// Runner for every Advent of Code 2024 day in this workspace.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every registered day with --all
+    Run(RunArgs),
+    /// Check answers against each day's data/answer.txt
+    Verify(VerifyArgs),
+    /// Solve one day for every *.txt input in a directory
+    Batch(BatchArgs),
+    /// Time parsing and both parts, and track the timings over time
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Only run this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Run every registered day in order
+    #[arg(long)]
+    all: bool,
+    /// Workspace root containing the dayN/data/input.txt files
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+    /// Input file for the selected day instead of its data/input.txt, or - for stdin
+    #[arg(long, requires = "day")]
+    input: Option<InputSource>,
+    /// Fail on the first malformed input line instead of warning
+    #[arg(long)]
+    strict: bool,
+    /// Result format
+    #[arg(long, value_enum, default_value_t)]
+    output: OutputFormat,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day; every registered day otherwise
+    #[arg(long)]
+    day: Option<u8>,
+    /// Workspace root containing the dayN/data directories
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Day to run
+    #[arg(long)]
+    day: u8,
+    /// Only run this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Directory of *.txt inputs, with optional *.answer.txt siblings
+    dir: PathBuf,
+    /// Fail an input on its first malformed line instead of warning
+    #[arg(long)]
+    strict: bool,
+    /// Result format
+    #[arg(long, value_enum, default_value_t)]
+    output: OutputFormat,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day; every registered day otherwise
+    #[arg(long)]
+    day: Option<u8>,
+    /// Timed iterations per step
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+    /// Untimed iterations per step before measuring
+    #[arg(long, default_value_t = 2)]
+    warmup: usize,
+    /// JSON file the results are appended to
+    #[arg(long, default_value = "bench_history.json")]
+    history: PathBuf,
+    /// Flag steps whose median got slower by more than this many percent
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Compare against the history without appending this run
+    #[arg(long)]
+    no_save: bool,
+    /// Workspace root containing the dayN/data/input.txt files
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Batch(args) => batch(args),
+        Command::Bench(args) => bench(args),
+    }
+}
+
+fn select(day: Option<u8>) -> Result<Vec<Box<dyn Puzzle>>> {
+    match day {
+        Some(day) => Ok(vec![registry::find(day)
+            .ok_or_else(|| Error::InvalidInput(format!("Day {} is not registered", day)))?]),
+        None => Ok(registry::all()),
+    }
+}
+
+fn select_parts(part: Option<u8>) -> Vec<Part> {
+    match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    }
+}
+
+fn run(args: RunArgs) -> Result<ExitCode> {
+    let puzzles = select(args.day)?;
+    let parts = select_parts(args.part);
+
+    let mut results = Vec::with_capacity(puzzles.len());
+    for puzzle in &puzzles {
+        let source = match &args.input {
+            Some(source) => source.clone(),
+            None => InputSource::File(runner::input_path(&args.root, puzzle.day())),
+        };
+        let diag = Diagnostics::new(args.strict).with_file(source.to_string());
+        results.push(runner::run(puzzle.as_ref(), &source.read_to_string()?, diag, &parts)?);
+    }
+
+    if args.output == OutputFormat::Text {
+        for warning in results.iter().flat_map(|result| &result.warnings) {
+            eprintln!("warning: {}", warning);
+        }
+        runner::print_table(&results);
+    } else {
+        let records: Vec<_> = results.iter().flat_map(output::records).collect();
+        output::print_records(args.output, &records)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(args: VerifyArgs) -> Result<ExitCode> {
+    let mut checks = Vec::new();
+    for puzzle in select(args.day)? {
+        checks.extend(verify::verify(puzzle.as_ref(), &args.root)?);
+    }
+
+    verify::print_checks(&checks);
+
+    let failures = checks.iter().filter(|check| !check.passed()).count();
+    if failures > 0 {
+        eprintln!("\n{} of {} answers did not match", failures, checks.len());
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn batch(args: BatchArgs) -> Result<ExitCode> {
+    let puzzle = select(Some(args.day))?.remove(0);
+    let parts = select_parts(args.part);
+    let entries = batch::run(puzzle.as_ref(), &args.dir, args.strict, &parts)?;
+
+    if entries.is_empty() {
+        return Err(Error::InvalidInput(format!("No *.txt inputs found in {}", args.dir.display())));
+    }
+    if args.output == OutputFormat::Text {
+        batch::print_entries(&entries);
+    } else {
+        let mut records = Vec::new();
+        for entry in &entries {
+            match &entry.outcome {
+                Ok(result) => records.extend(output::records(result)),
+                Err(err) => records.extend(output::failed_records(
+                    args.day,
+                    &parts,
+                    &entry.path.display().to_string(),
+                    err,
+                )),
+            }
+        }
+        output::print_records(args.output, &records)?;
+    }
+
+    let failed = entries
+        .iter()
+        .any(|entry| matches!(entry.status(), MatchStatus::Fail | MatchStatus::Error | MatchStatus::AnswerError));
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn bench(args: BenchArgs) -> Result<ExitCode> {
+    let mut timings = Vec::new();
+    for puzzle in select(args.day)? {
+        let content = input::read_to_string(runner::input_path(&args.root, puzzle.day()))?;
+        timings.extend(bench::bench_day(puzzle.as_ref(), &content, args.warmup, args.iterations)?);
+    }
+
+    let mut history = bench::load_history(&args.history)?;
+    let run = bench::new_run(timings, args.warmup, args.iterations);
+    let regressions = bench::report(&run, &history, args.threshold);
+    if regressions > 0 {
+        eprintln!("\n{} step(s) regressed by more than {}%", regressions, args.threshold);
+    }
+
+    if !args.no_save {
+        history.push(run);
+        bench::save_history(&args.history, &history)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,124 @@
+use std::fmt::Write;
+
+use aoc_common::{Error, Part, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::runner::{nanos, DayResult};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable table
+    #[default]
+    Text,
+    /// JSON array of records
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// One answered part in the machine-readable output. Field names and order
+/// are part of the output format; only ever append new fields.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// `None` when the input could not be solved; see `diagnostics`.
+    pub answer: Option<String>,
+    pub elapsed_ns: u64,
+    pub parse_ns: u64,
+    pub input: String,
+    pub input_sha256: String,
+    pub diagnostics: Vec<String>,
+}
+
+const CSV_HEADER: &str = "day,part,answer,elapsed_ns,parse_ns,input,input_sha256,diagnostics";
+
+/// Hex SHA-256 of an input, to tell apart results computed on different data.
+pub fn input_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+pub fn records(result: &DayResult) -> Vec<Record> {
+    let diagnostics: Vec<String> = result.warnings.iter().map(|w| w.to_string()).collect();
+    result
+        .parts
+        .iter()
+        .map(|part| Record {
+            day: result.day,
+            part: part.part.number(),
+            answer: Some(part.answer.to_string()),
+            elapsed_ns: nanos(part.elapsed),
+            parse_ns: nanos(result.parse_time),
+            input: result.input.clone(),
+            input_sha256: result.input_hash.clone(),
+            diagnostics: diagnostics.clone(),
+        })
+        .collect()
+}
+
+/// Records for an input that failed before producing any answer.
+pub fn failed_records(day: u8, parts: &[Part], input: &str, error: &Error) -> Vec<Record> {
+    parts
+        .iter()
+        .map(|part| Record {
+            day,
+            part: part.number(),
+            answer: None,
+            elapsed_ns: 0,
+            parse_ns: 0,
+            input: input.to_string(),
+            input_sha256: String::new(),
+            diagnostics: vec![error.to_string()],
+        })
+        .collect()
+}
+
+pub fn print_records(format: OutputFormat, records: &[Record]) -> Result<()> {
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(records)
+                .map_err(|err| Error::InvalidInput(err.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Csv => {
+            println!("{}", CSV_HEADER);
+            for record in records {
+                println!("{}", csv_row(record));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn csv_row(record: &Record) -> String {
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        record.answer.clone().unwrap_or_default(),
+        record.elapsed_ns.to_string(),
+        record.parse_ns.to_string(),
+        record.input.clone(),
+        record.input_sha256.clone(),
+        record.diagnostics.join("; "),
+    ]
+    .iter()
+    .map(|field| csv_field(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
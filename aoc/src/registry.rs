@@ -0,0 +1,18 @@
+use aoc_common::Puzzle;
+
+/// Every solved day, in calendar order.
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day1::Day1),
+        Box::new(day2::Day2),
+        Box::new(day3::Day3),
+        Box::new(day4::Day4),
+        Box::new(day5::Day5),
+        Box::new(day6::Day6),
+        Box::new(day7::Day7),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Puzzle>> {
+    all().into_iter().find(|puzzle| puzzle.day() == day)
+}
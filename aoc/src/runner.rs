@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc_common::input::DEFAULT_INPUT;
+use aoc_common::{Answer, Diagnostics, ParseError, Part, Puzzle, Result};
+
+use crate::output;
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    /// Name of the input file, as given to the diagnostics.
+    pub input: String,
+    /// Hex SHA-256 of the raw input.
+    pub input_hash: String,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+    /// Malformed lines the parser skipped.
+    pub warnings: Vec<ParseError>,
+}
+
+impl DayResult {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.parts.iter().find(|p| p.part == part).map(|p| &p.answer)
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+pub fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Location of a day's puzzle input below the workspace root.
+pub fn input_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{}", day)).join(DEFAULT_INPUT)
+}
+
+/// Parse `input` once and answer the requested parts, timing each step.
+pub fn run(puzzle: &dyn Puzzle, input: &str, mut diag: Diagnostics, parts: &[Part]) -> Result<DayResult> {
+    let start = Instant::now();
+    let parsed = puzzle.parse_input(input, &mut diag)?;
+    let parse_time = start.elapsed();
+
+    let mut results = Vec::with_capacity(parts.len());
+    for &part in parts {
+        let start = Instant::now();
+        let answer = puzzle.solve_part(parsed.as_ref(), part)?;
+        results.push(PartResult { part, answer, elapsed: start.elapsed() });
+    }
+
+    Ok(DayResult {
+        day: puzzle.day(),
+        title: puzzle.title(),
+        input: diag.file().unwrap_or("<input>").to_string(),
+        input_hash: output::input_hash(input),
+        parse_time,
+        parts: results,
+        warnings: diag.into_warnings(),
+    })
+}
+
+pub fn print_table(results: &[DayResult]) {
+    let rows: Vec<[String; 5]> = results
+        .iter()
+        .map(|result| {
+            let answer = |part| result.answer(part).map_or("-".to_string(), |a| a.to_string());
+            [
+                result.day.to_string(),
+                result.title.to_string(),
+                answer(Part::One),
+                answer(Part::Two),
+                format!("{:.2?}", result.total_time()),
+            ]
+        })
+        .collect();
+    print_rows(&["Day", "Title", "Part 1", "Part 2", "Time"], &rows);
+}
+
+/// Print `rows` as a left-aligned table under `header`.
+pub fn print_rows<const N: usize>(header: &[&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", padded.join(" | ").trim_end());
+    };
+
+    line(header.to_vec());
+    println!("{}", widths.map(|w| "-".repeat(w)).join("-+-"));
+    for row in rows {
+        line(row.iter().map(String::as_str).collect());
+    }
+}
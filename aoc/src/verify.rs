@@ -0,0 +1,62 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::{self, DEFAULT_ANSWER};
+use aoc_common::{Answer, Diagnostics, Part, Puzzle, Result};
+
+use crate::runner;
+
+/// Outcome of comparing one part against its stored answer.
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub expected: Option<String>,
+    pub actual: Answer,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.expected.as_deref() == Some(self.actual.as_str())
+    }
+}
+
+/// Location of a day's accepted answers below the workspace root.
+pub fn answer_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{}", day)).join(DEFAULT_ANSWER)
+}
+
+/// Solve both parts of `puzzle` against its input and compare each answer
+/// with the matching line of `data/answer.txt`.
+pub fn verify(puzzle: &dyn Puzzle, root: &Path) -> Result<Vec<Check>> {
+    let path = runner::input_path(root, puzzle.day());
+    let content = input::read_to_string(&path)?;
+    let expected = input::read_answers(answer_path(root, puzzle.day()))?;
+    let diag = Diagnostics::new(false).with_file(path.display().to_string());
+    let result = runner::run(puzzle, &content, diag, &Part::BOTH)?;
+
+    Ok(result
+        .parts
+        .into_iter()
+        .map(|part| Check {
+            day: result.day,
+            part: part.part,
+            expected: expected.get(part.part.number() as usize - 1).cloned(),
+            actual: part.answer,
+        })
+        .collect())
+}
+
+pub fn print_checks(checks: &[Check]) {
+    let rows: Vec<[String; 5]> = checks
+        .iter()
+        .map(|check| {
+            [
+                check.day.to_string(),
+                check.part.number().to_string(),
+                check.expected.clone().unwrap_or_else(|| "(missing)".to_string()),
+                check.actual.to_string(),
+                if check.passed() { "PASS" } else { "FAIL" }.to_string(),
+            ]
+        })
+        .collect();
+    runner::print_rows(&["Day", "Part", "Expected", "Actual", "Status"], &rows);
+}
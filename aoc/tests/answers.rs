@@ -0,0 +1,46 @@
+//! Regression suite: every registered day must still reproduce the answers
+//! stored in its `data/answer.txt`.
+
+use std::path::Path;
+
+use aoc::{registry, verify};
+
+fn check_day(day: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let puzzle = registry::find(day).expect("day is registered");
+
+    for check in verify::verify(puzzle.as_ref(), root).unwrap() {
+        assert_eq!(
+            check.expected.as_deref(),
+            Some(check.actual.as_str()),
+            "day {} part {}",
+            check.day,
+            check.part.number()
+        );
+    }
+}
+
+macro_rules! answer_tests {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        $(#[test]
+        fn $name() {
+            check_day($day);
+        })*
+    };
+}
+
+answer_tests! {
+    day1 => 1,
+    day2 => 2,
+    day3 => 3,
+    day4 => 4,
+    day5 => 5,
+    day6 => 6,
+    day7 => 7,
+}
+
+#[test]
+fn every_day_is_covered() {
+    let days: Vec<u8> = registry::all().iter().map(|puzzle| puzzle.day()).collect();
+    assert_eq!(days, (1..=7).collect::<Vec<u8>>());
+}
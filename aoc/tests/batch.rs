@@ -0,0 +1,25 @@
+use std::fs;
+
+use aoc::batch::{self, MatchStatus};
+use aoc::registry;
+use aoc_common::Part;
+
+const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+#[test]
+fn unreadable_answer_file_is_reported_per_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("alice.txt"), EXAMPLE).unwrap();
+    fs::write(dir.path().join("alice.answer.txt"), "11\n31\n").unwrap();
+    fs::write(dir.path().join("bob.txt"), EXAMPLE).unwrap();
+    fs::write(dir.path().join("bob.answer.txt"), b"\xff\xfe not utf-8").unwrap();
+
+    let puzzle = registry::find(1).unwrap();
+    let entries = batch::run(puzzle.as_ref(), dir.path(), true, &[Part::One, Part::Two]).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert!(matches!(entries[0].status(), MatchStatus::Pass));
+    assert!(matches!(entries[1].status(), MatchStatus::AnswerError));
+    assert!(entries[1].outcome.is_ok());
+    assert!(matches!(entries[1].expected, Some(Err(_))));
+}
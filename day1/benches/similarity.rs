@@ -0,0 +1,70 @@
+//! Similarity score on generated lists: the merge join in
+//! `day1::similarity_score` against the original quadratic scan.
+//!
+//! Run with `cargo bench -p day1`. At 10^6 lines the full quadratic scan
+//! takes several minutes, so by default it only scans the right list for
+//! every thousandth left id and scales the time up, marked with `~`;
+//! `cargo bench -p day1 -- --full` times the whole scan.
+
+use aoc_common::bench::measure;
+use aoc_common::testing::Rng;
+use day1::similarity_score;
+
+const SIZES: [usize; 4] = [1_000, 10_000, 100_000, 1_000_000];
+
+/// Share of the left ids the quadratic scan looks up at 10^6 lines without
+/// `--full`.
+const SAMPLE: usize = 1_000;
+
+/// The original part 2: count every left id's matches by scanning the whole
+/// right list.
+fn similarity_score_quadratic(left_numbers: &[i64], right_numbers: &[i64]) -> i64 {
+    let mut score = 0;
+    for left in left_numbers.iter() {
+        let count = right_numbers.iter()
+            .filter(|&right| right == left)
+            .count();
+        score += left * count as i64;
+    }
+    score
+}
+
+/// Sorted list of `len` five-digit ids, like the puzzle input's.
+fn generate(len: usize, seed: u64) -> Vec<i64> {
+    let mut rng = Rng::new(seed);
+    let mut ids: Vec<i64> = (0..len).map(|_| 10_000 + rng.below(90_000) as i64).collect();
+    ids.sort();
+    ids
+}
+
+fn main() {
+    let full = std::env::args().any(|arg| arg == "--full");
+
+    println!("{:>9} | {:>12} | {:>12}", "lines", "merge join", "quadratic");
+    for len in SIZES {
+        let left = generate(len, 0x9E37_79B9_7F4A_7C15);
+        let right = generate(len, 0xD1B5_4A32_D192_ED03);
+
+        let fast = measure(1, 10, || similarity_score(&left, &right));
+        if len >= 1_000_000 && !full {
+            // Every left id scans the whole right list, so the time is
+            // proportional to the number of left ids looked up.
+            let sample = &left[..len / SAMPLE];
+            let slow = measure(0, 1, || similarity_score_quadratic(sample, &right));
+            let estimate = format!("~{:.2?}", slow.median() * SAMPLE as u32);
+            println!("{:>9} | {:>12.2?} | {:>12}", len, fast.median(), estimate);
+            continue;
+        }
+
+        let iterations = if len >= 100_000 { 1 } else { 5 };
+        let slow = measure(0, iterations, || similarity_score_quadratic(&left, &right));
+        if len < 100_000 {
+            assert_eq!(
+                similarity_score(&left, &right).unwrap(),
+                similarity_score_quadratic(&left, &right)
+            );
+        }
+
+        println!("{:>9} | {:>12.2?} | {:>12.2?}", len, fast.median(), slow.median());
+    }
+}
@@ -0,0 +1,232 @@
+//! External-memory variant of [`Lists`](crate::Lists) for inputs that do not
+//! fit in memory.
+//!
+//! Ids are collected into runs of at most `run_len` values; each full run is
+//! sorted and spilled to a temporary file. The distance and the similarity
+//! score are then computed over a k-way merge of the runs, so only one
+//! buffered reader per run is held in memory.
+//!
+//! Run files are only opened while they are merged. When a list spills more
+//! than [`MAX_FAN_IN`] runs, groups of them are first merged into longer
+//! runs, so no merge holds more than that many files open at once.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+use aoc_common::{Diagnostics, Result};
+use tempfile::{NamedTempFile, TempPath};
+
+use crate::{check_lengths, distance_of, parse_pair, similarity_of};
+
+/// Number of ids per run when none is given.
+pub const DEFAULT_RUN_LEN: usize = 1 << 20;
+
+/// Most runs merged at once, and so most run files open per list.
+pub const MAX_FAN_IN: usize = 64;
+
+/// Both columns of the input, spilled to disk as sorted runs.
+pub struct ExternalLists {
+    left: Runs,
+    right: Runs,
+    /// See [`Lists::tolerance`](crate::Lists::tolerance).
+    pub tolerance: usize,
+}
+
+impl ExternalLists {
+    /// Read pairs line by line, with the same validation as
+    /// [`parse_lists`](crate::parse_lists).
+    pub fn read<R: BufRead>(
+        reader: R,
+        diag: &mut Diagnostics,
+        tolerance: usize,
+        run_len: usize,
+    ) -> Result<ExternalLists> {
+        let mut left = Runs::new(run_len);
+        let mut right = Runs::new(run_len);
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let (l, r) = parse_pair(index, &line, diag)?;
+            if let Some(id) = l {
+                left.push(id)?;
+            }
+            if let Some(id) = r {
+                right.push(id)?;
+            }
+        }
+        left.spill()?;
+        right.spill()?;
+        left.compact()?;
+        right.compact()?;
+
+        Ok(ExternalLists { left, right, tolerance })
+    }
+
+    /// Number of ids in the left and right list.
+    pub fn lens(&self) -> (usize, usize) {
+        (self.left.len, self.right.len)
+    }
+
+    /// Number of sorted runs left to merge for the left and right list,
+    /// never more than [`MAX_FAN_IN`] each.
+    pub fn run_counts(&self) -> (usize, usize) {
+        (self.left.runs.len(), self.right.runs.len())
+    }
+
+    /// Fail unless the lists differ in length by at most `tolerance` ids.
+    pub fn check_lengths(&self) -> Result<()> {
+        let (left, right) = self.lens();
+        check_lengths(left, right, self.tolerance)
+    }
+
+    /// Same as [`total_distance`](crate::total_distance) on the sorted lists.
+    pub fn total_distance(&self) -> Result<i64> {
+        self.check_lengths()?;
+        distance_of(self.left.merge()?, self.right.merge()?)
+    }
+
+    /// Same as [`similarity_score`](crate::similarity_score) on the sorted lists.
+    pub fn similarity_score(&self) -> Result<i64> {
+        similarity_of(self.left.merge()?, self.right.merge()?)
+    }
+}
+
+/// One spilled, sorted run of ids. The file is deleted when the run is
+/// dropped.
+struct Run {
+    path: TempPath,
+    len: usize,
+}
+
+impl Run {
+    /// Write ascending `ids` out as a new run.
+    fn write(ids: impl IntoIterator<Item = Result<i64>>) -> Result<Run> {
+        let mut writer = BufWriter::new(NamedTempFile::new()?);
+        let mut len = 0;
+        for id in ids {
+            writer.write_all(&id?.to_le_bytes())?;
+            len += 1;
+        }
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        Ok(Run { path: file.into_temp_path(), len })
+    }
+}
+
+/// The runs of one list, plus the ids not yet spilled.
+struct Runs {
+    buffer: Vec<i64>,
+    run_len: usize,
+    runs: Vec<Run>,
+    len: usize,
+}
+
+impl Runs {
+    fn new(run_len: usize) -> Runs {
+        let run_len = run_len.max(1);
+        let buffer = Vec::with_capacity(run_len.min(DEFAULT_RUN_LEN));
+        Runs { buffer, run_len, runs: Vec::new(), len: 0 }
+    }
+
+    fn push(&mut self, id: i64) -> Result<()> {
+        self.buffer.push(id);
+        self.len += 1;
+        if self.buffer.len() >= self.run_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Sort the buffered ids and write them out as a new run.
+    fn spill(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.buffer.sort_unstable();
+
+        let run = Run::write(self.buffer.iter().copied().map(Ok))?;
+        self.runs.push(run);
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Merge groups of [`MAX_FAN_IN`] runs into longer runs, pass after pass,
+    /// until no more than that many are left.
+    fn compact(&mut self) -> Result<()> {
+        while self.runs.len() > MAX_FAN_IN {
+            let mut runs = std::mem::take(&mut self.runs).into_iter();
+            loop {
+                // Each group is dropped, deleting its files, once merged
+                let group: Vec<Run> = runs.by_ref().take(MAX_FAN_IN).collect();
+                if group.is_empty() {
+                    break;
+                }
+                self.runs.push(Run::write(Merge::new(&group)?)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// A fresh ascending stream over all runs.
+    fn merge(&self) -> Result<Merge> {
+        Merge::new(&self.runs)
+    }
+}
+
+/// Sequential reader over one run.
+struct RunReader {
+    reader: BufReader<File>,
+    remaining: usize,
+}
+
+impl RunReader {
+    fn next_id(&mut self) -> io::Result<Option<i64>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let mut bytes = [0; 8];
+        self.reader.read_exact(&mut bytes)?;
+        self.remaining -= 1;
+        Ok(Some(i64::from_le_bytes(bytes)))
+    }
+}
+
+/// K-way merge of sorted runs, smallest id first.
+struct Merge {
+    readers: Vec<RunReader>,
+    heap: BinaryHeap<Reverse<(i64, usize)>>,
+}
+
+impl Merge {
+    /// Open every run for reading; the files are closed with the merge.
+    fn new(runs: &[Run]) -> Result<Merge> {
+        let mut readers = Vec::with_capacity(runs.len());
+        let mut heap = BinaryHeap::with_capacity(runs.len());
+
+        for (index, run) in runs.iter().enumerate() {
+            let file = File::open(&run.path)?;
+            let mut reader = RunReader { reader: BufReader::new(file), remaining: run.len };
+            if let Some(id) = reader.next_id()? {
+                heap.push(Reverse((id, index)));
+            }
+            readers.push(reader);
+        }
+
+        Ok(Merge { readers, heap })
+    }
+}
+
+impl Iterator for Merge {
+    type Item = Result<i64>;
+
+    fn next(&mut self) -> Option<Result<i64>> {
+        let Reverse((id, index)) = self.heap.pop()?;
+        match self.readers[index].next_id() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, index))),
+            Ok(None) => {}
+            Err(err) => return Some(Err(err.into())),
+        }
+        Some(Ok(id))
+    }
+}
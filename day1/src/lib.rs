@@ -0,0 +1,193 @@
+//! Day 1: Historian Hysteria.
+//!
+//! Two columns of location ids are compared after sorting: part 1 sums the
+//! distance between paired ids, part 2 weights every left id by how often it
+//! appears on the right.
+//!
+//! ```
+//! use aoc_common::{Diagnostics, Solution};
+//! use day1::Day1;
+//!
+//! let lists = Day1::parse("3   4\n4   3\n2   5\n", &mut Diagnostics::default()).unwrap();
+//! assert_eq!(lists.left_numbers, [2, 3, 4]);
+//! assert_eq!(Day1::part1(&lists).unwrap().as_str(), "3");
+//! ```
+
+pub mod external;
+pub mod metric;
+pub mod stats;
+
+use aoc_common::{Answer, Diagnostics, Error, ParseError, Result, Solution};
+
+pub struct Day1;
+
+/// Both columns of the input, each sorted ascending.
+pub struct Lists {
+    pub left_numbers: Vec<i64>,
+    pub right_numbers: Vec<i64>,
+    /// Complete pairs in input order, for metrics that depend on which ids
+    /// were listed side by side.
+    pub pairs: Vec<(i64, i64)>,
+    /// How many more ids one list may hold than the other before part 1
+    /// refuses to pair them up.
+    pub tolerance: usize,
+}
+
+impl Lists {
+    /// Fail unless the lists differ in length by at most `tolerance` ids.
+    pub fn check_lengths(&self) -> Result<()> {
+        check_lengths(self.left_numbers.len(), self.right_numbers.len(), self.tolerance)
+    }
+}
+
+fn check_lengths(left: usize, right: usize, tolerance: usize) -> Result<()> {
+    if left.abs_diff(right) > tolerance {
+        return Err(Error::InvalidInput(format!(
+            "Lists differ in length: {} left ids, {} right ids (tolerance {})",
+            left, right, tolerance
+        )));
+    }
+    Ok(())
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Lists;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Lists> {
+        parse_lists(input, diag, 0)
+    }
+
+    fn part1(lists: &Lists) -> Result<Answer> {
+        lists.check_lengths()?;
+        Ok(total_distance(&lists.left_numbers, &lists.right_numbers)?.into())
+    }
+
+    fn part2(lists: &Lists) -> Result<Answer> {
+        Ok(similarity_score(&lists.left_numbers, &lists.right_numbers)?.into())
+    }
+}
+
+/// Parse the two columns. Each line is validated as a pair: when an id is
+/// missing or malformed the line is reported and its valid half kept, so the
+/// lists may end up of different lengths. Part 1 then refuses to pair them
+/// up unless they are at most `tolerance` ids apart.
+pub fn parse_lists(input: &str, diag: &mut Diagnostics, tolerance: usize) -> Result<Lists> {
+    let mut left_numbers: Vec<i64> = Vec::new();
+    let mut right_numbers: Vec<i64> = Vec::new();
+    let mut pairs: Vec<(i64, i64)> = Vec::new();
+
+    for (index, ip) in input.lines().enumerate() {
+        let (left, right) = parse_pair(index, ip, diag)?;
+        if let (Some(l), Some(r)) = (left, right) {
+            pairs.push((l, r));
+        }
+        left_numbers.extend(left);
+        right_numbers.extend(right);
+    }
+
+    // Sort Array
+    left_numbers.sort();
+    right_numbers.sort();
+
+    Ok(Lists { left_numbers, right_numbers, pairs, tolerance })
+}
+
+/// The ids of one input line that should be kept, see [`parse_lists`].
+fn parse_pair(index: usize, ip: &str, diag: &mut Diagnostics) -> Result<(Option<i64>, Option<i64>)> {
+    let numbers: Vec<&str> = ip.split_whitespace().collect();
+    if numbers.is_empty() {
+        return Ok((None, None));
+    }
+    if numbers.len() < 2 {
+        diag.report(ParseError::new(index, ip, ip, "Expected two location ids"))?;
+        // An indented id is the right one
+        let id = numbers[0].parse::<i64>().ok();
+        return Ok(if ip.starts_with(char::is_whitespace) { (None, id) } else { (id, None) });
+    }
+    if let Some(extra) = numbers.get(2) {
+        diag.report(ParseError::new(index, ip, extra, "Unexpected trailing value"))?;
+    }
+
+    match (numbers[0].parse::<i64>(), numbers[1].parse::<i64>()) {
+        (Ok(left), Ok(right)) => Ok((Some(left), Some(right))),
+        (left, right) => {
+            for (token, side) in [(numbers[0], &left), (numbers[1], &right)] {
+                if side.is_err() {
+                    let message = "Half-parsed pair, invalid location id";
+                    diag.report(ParseError::new(index, ip, token, message))?;
+                }
+            }
+            Ok((left.ok(), right.ok()))
+        }
+    }
+}
+
+/// Sum of `|left - right|` over the pairs of two sorted lists.
+pub fn total_distance(left_numbers: &[i64], right_numbers: &[i64]) -> Result<i64> {
+    distance_of(left_numbers.iter().copied().map(Ok), right_numbers.iter().copied().map(Ok))
+}
+
+/// Sum of every left id multiplied by its number of occurrences on the right.
+///
+/// Both lists must be sorted ascending, as [`Lists`] keeps them. They are
+/// walked together once, so this is linear in their combined length.
+pub fn similarity_score(left_numbers: &[i64], right_numbers: &[i64]) -> Result<i64> {
+    debug_assert!(left_numbers.is_sorted() && right_numbers.is_sorted());
+    similarity_of(left_numbers.iter().copied().map(Ok), right_numbers.iter().copied().map(Ok))
+}
+
+/// [`total_distance`] over two sorted streams of ids.
+fn distance_of<L, R>(left_numbers: L, right_numbers: R) -> Result<i64>
+where
+    L: IntoIterator<Item = Result<i64>>,
+    R: IntoIterator<Item = Result<i64>>,
+{
+    let overflow = || Error::Overflow("Total distance does not fit in 64 bits".into());
+
+    let mut distance: i64 = 0;
+    for (left, right) in left_numbers.into_iter().zip(right_numbers) {
+        let diff = left?.checked_sub(right?).and_then(i64::checked_abs).ok_or_else(overflow)?;
+        distance = distance.checked_add(diff).ok_or_else(overflow)?;
+    }
+    Ok(distance)
+}
+
+/// [`similarity_score`] over two sorted streams of ids.
+fn similarity_of<L, R>(left_numbers: L, right_numbers: R) -> Result<i64>
+where
+    L: IntoIterator<Item = Result<i64>>,
+    R: IntoIterator<Item = Result<i64>>,
+{
+    let overflow = || Error::Overflow("Similarity score does not fit in 64 bits".into());
+
+    let mut right_numbers = right_numbers.into_iter();
+    let mut right = right_numbers.next().transpose()?;
+    let mut score: i64 = 0;
+    let mut previous: Option<(i64, i64)> = None;
+    for left in left_numbers {
+        let left = left?;
+        let count = match previous {
+            // Repeated left ids reuse the count of the first one.
+            Some((id, count)) if id == left => count,
+            _ => {
+                while right.is_some_and(|id| id < left) {
+                    right = right_numbers.next().transpose()?;
+                }
+                let mut count: i64 = 0;
+                while right == Some(left) {
+                    count += 1;
+                    right = right_numbers.next().transpose()?;
+                }
+                count
+            }
+        };
+        previous = Some((left, count));
+
+        let sim = left.checked_mul(count).ok_or_else(overflow)?;
+        score = score.checked_add(sim).ok_or_else(overflow)?;
+    }
+    Ok(score)
+}
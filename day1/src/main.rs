@@ -1,58 +1,59 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use aoc_common::input::InputArgs;
+use aoc_common::Solution;
+use clap::Parser;
+use day1::external::{ExternalLists, DEFAULT_RUN_LEN};
+use day1::metric::DistanceMetric;
+use day1::stats::stats;
+use day1::{parse_lists, Day1};
 
-fn main() -> io::Result<()> {
-    let mut left_numbers: Vec<i32> = Vec::new();
-    let mut right_numbers: Vec<i32> = Vec::new();
+/// Day 1: Historian Hysteria
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Allow the lists to differ in length by up to this many ids, as when
+    /// broken lines leave only one valid id
+    #[arg(long, default_value_t = 0)]
+    tolerance: usize,
+    /// Sort the lists on disk instead of in memory, for inputs larger than RAM
+    #[arg(long)]
+    external: bool,
+    /// Number of ids per sorted run in external mode
+    #[arg(long, default_value_t = DEFAULT_RUN_LEN, requires = "external")]
+    run_len: usize,
+    /// How to measure the distance between the lists
+    #[arg(long, value_enum, default_value_t, conflicts_with = "external")]
+    metric: DistanceMetric,
+    /// Print statistics about both lists instead of the answers
+    #[arg(long, conflicts_with = "external")]
+    stats: bool,
+    /// Number of entries in each ranking of the statistics
+    #[arg(long, default_value_t = 5, requires = "stats")]
+    top: usize,
+}
 
-    if let Ok(lines) = read_lines("data/input.txt") {
-        for line in lines {
-            if let Ok(ip) = line {
-                let numbers: Vec<&str> = ip.split_whitespace().collect();
-                if numbers.len() >= 2 {
-                    if let Ok(left) = numbers[0].parse::<i32>() {
-                        left_numbers.push(left);
-                    }
-                    if let Ok(right) = numbers[1].parse::<i32>() {
-                        right_numbers.push(right);
-                    }
-                }
-            }
-        }
-    }
- 
-    // Sort Array
-    left_numbers.sort();
-    right_numbers.sort();
-    
-    // Part 1
-    let mut distance = 0;
-    for (left, right) in left_numbers.iter().zip(right_numbers.iter()) {
-        let diff = left - right; 
-        distance = distance + diff.abs();
+fn main() -> aoc_common::Result<()> {
+    let cli = Cli::parse();
+    let mut diag = cli.input.diagnostics();
+    if cli.external {
+        let lists = ExternalLists::read(cli.input.input.open()?, &mut diag, cli.tolerance, cli.run_len)?;
+        diag.print_warnings();
+
+        println!("The Distance is: {}", lists.total_distance()?);
+        println!("Total Score is: {}", lists.similarity_score()?);
+        return Ok(());
     }
-    
-    // Part 2
-    let mut score = 0;
-    for left in left_numbers.iter() {
-        let count = right_numbers.iter()
-            .filter(|&right| right == left)
-            .count();
-        let sim = left * count as i32;
-        score = score + sim;
+
+    let lists = parse_lists(&cli.input.read_to_string()?, &mut diag, cli.tolerance)?;
+    diag.print_warnings();
+
+    if cli.stats {
+        print!("{}", stats(&lists, cli.top)?);
+        return Ok(());
     }
 
-    
-    println!("The Distance is: {}", distance);
-    println!("Total Score is: {}", score);
-    
+    println!("The Distance is: {}", cli.metric.distance(&lists)?);
+    println!("Total Score is: {}", Day1::part2(&lists)?);
+
     Ok(())
 }
-
-// Read File
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
\ No newline at end of file
@@ -0,0 +1,152 @@
+//! Ways to measure how far apart the two lists are.
+//!
+//! Part 1 uses [`DistanceMetric::L1`]; the others reuse the same parsing and
+//! sorting to compare list pairs in other contexts.
+
+use aoc_common::{Error, Result};
+use clap::ValueEnum;
+
+use crate::{total_distance, Lists};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum DistanceMetric {
+    /// Sum of absolute differences of the sorted lists
+    #[default]
+    L1,
+    /// Sum of squared differences of the sorted lists
+    L2Squared,
+    /// Largest absolute difference of the sorted lists
+    MaxDeviation,
+    /// Number of discordant pairs in the input pairing
+    KendallTau,
+    /// Earth mover's distance between the id histograms
+    EarthMovers,
+}
+
+impl DistanceMetric {
+    /// Distance between the two lists under this metric. The metrics that
+    /// pair up sorted ids refuse lists of different lengths, as part 1 does.
+    pub fn distance(self, lists: &Lists) -> Result<i64> {
+        let (left, right) = (&lists.left_numbers, &lists.right_numbers);
+        match self {
+            DistanceMetric::L1 => {
+                lists.check_lengths()?;
+                total_distance(left, right)
+            }
+            DistanceMetric::L2Squared => {
+                lists.check_lengths()?;
+                squared_distance(left, right)
+            }
+            DistanceMetric::MaxDeviation => {
+                lists.check_lengths()?;
+                max_deviation(left, right)
+            }
+            DistanceMetric::KendallTau => Ok(kendall_tau(&lists.pairs)),
+            DistanceMetric::EarthMovers => earth_movers(left, right),
+        }
+    }
+}
+
+fn overflow(metric: &str) -> Error {
+    Error::Overflow(format!("{} does not fit in 64 bits", metric))
+}
+
+/// Absolute difference of each pair of ids, in order.
+fn deviations<'a>(left: &'a [i64], right: &'a [i64]) -> impl Iterator<Item = Result<i64>> + 'a {
+    left.iter().zip(right.iter()).map(|(l, r)| {
+        l.checked_sub(*r).and_then(i64::checked_abs).ok_or_else(|| overflow("Deviation"))
+    })
+}
+
+/// Sum of `(left - right)²` over the pairs of two sorted lists.
+pub fn squared_distance(left: &[i64], right: &[i64]) -> Result<i64> {
+    let mut distance: i64 = 0;
+    for diff in deviations(left, right) {
+        let diff = diff?;
+        let square = diff.checked_mul(diff).ok_or_else(|| overflow("Squared distance"))?;
+        distance = distance.checked_add(square).ok_or_else(|| overflow("Squared distance"))?;
+    }
+    Ok(distance)
+}
+
+/// Largest `|left - right|` over the pairs of two sorted lists, 0 when empty.
+pub fn max_deviation(left: &[i64], right: &[i64]) -> Result<i64> {
+    let mut max = 0;
+    for diff in deviations(left, right) {
+        max = max.max(diff?);
+    }
+    Ok(max)
+}
+
+/// Number of pairs of lines whose ids are ordered one way on the left and
+/// the opposite way on the right. Ties on either side are not discordant.
+pub fn kendall_tau(pairs: &[(i64, i64)]) -> i64 {
+    let mut pairs = pairs.to_vec();
+    pairs.sort();
+
+    // Ties on the left are sorted by their right id, so they add no
+    // inversions; every remaining inversion is a discordant pair.
+    let mut rights: Vec<i64> = pairs.iter().map(|&(_, right)| right).collect();
+    let mut buffer = vec![0; rights.len()];
+    count_inversions(&mut rights, &mut buffer)
+}
+
+/// Merge sort `values` and count the pairs `i < j` with `values[i] > values[j]`.
+fn count_inversions(values: &mut [i64], buffer: &mut [i64]) -> i64 {
+    let len = values.len();
+    if len < 2 {
+        return 0;
+    }
+    let mid = len / 2;
+    let mut inversions = count_inversions(&mut values[..mid], &mut buffer[..mid])
+        + count_inversions(&mut values[mid..], &mut buffer[mid..]);
+
+    let (mut i, mut j) = (0, mid);
+    for slot in buffer[..len].iter_mut() {
+        if j == len || (i < mid && values[i] <= values[j]) {
+            *slot = values[i];
+            i += 1;
+        } else {
+            *slot = values[j];
+            inversions += (mid - i) as i64;
+            j += 1;
+        }
+    }
+    values.copy_from_slice(&buffer[..len]);
+    inversions
+}
+
+/// Earth mover's distance between the histograms of two sorted lists, with
+/// every id carrying one unit of mass: the area between their cumulative
+/// counts. Lists of different lengths are compared by raw counts, so their
+/// surplus is carried up to the largest id.
+pub fn earth_movers(left: &[i64], right: &[i64]) -> Result<i64> {
+    debug_assert!(left.is_sorted() && right.is_sorted());
+
+    let (mut l, mut r) = (0, 0);
+    let mut work: i64 = 0;
+    let mut position: Option<i64> = None;
+    while l < left.len() || r < right.len() {
+        let next = match (left.get(l), right.get(r)) {
+            (Some(&a), Some(&b)) => a.min(b),
+            (Some(&a), None) => a,
+            (None, Some(&b)) => b,
+            (None, None) => unreachable!(),
+        };
+        if let Some(position) = position {
+            // Mass still waiting to be moved across the gap to `next`.
+            let surplus = (l as i64 - r as i64).abs();
+            let gap = next.checked_sub(position).ok_or_else(|| overflow("Earth mover's distance"))?;
+            let cost = surplus.checked_mul(gap).ok_or_else(|| overflow("Earth mover's distance"))?;
+            work = work.checked_add(cost).ok_or_else(|| overflow("Earth mover's distance"))?;
+        }
+        while left.get(l) == Some(&next) {
+            l += 1;
+        }
+        while right.get(r) == Some(&next) {
+            r += 1;
+        }
+        position = Some(next);
+    }
+    Ok(work)
+}
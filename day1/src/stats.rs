@@ -0,0 +1,137 @@
+//! Summary of the two lists, for inspecting an input by hand.
+
+use std::fmt;
+
+use aoc_common::{Error, Result};
+
+use crate::Lists;
+
+/// Figures about one sorted list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStats {
+    pub count: usize,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    /// Mean of the two middle ids when the count is even.
+    pub median: Option<f64>,
+    pub distinct: usize,
+    /// Ids occurring more than once with their counts, most frequent first.
+    pub most_duplicated: Vec<(i64, usize)>,
+}
+
+/// How much one id shared by both lists adds to the similarity score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contribution {
+    pub id: i64,
+    pub left_count: usize,
+    pub right_count: usize,
+    /// `id * left_count * right_count`
+    pub score: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub left: ListStats,
+    pub right: ListStats,
+    /// Number of distinct ids found in both lists.
+    pub intersection: usize,
+    /// Largest contributions to the similarity score, largest first.
+    pub top_contributors: Vec<Contribution>,
+}
+
+/// Gather the statistics of both lists, keeping at most `top` entries in
+/// each ranking.
+pub fn stats(lists: &Lists, top: usize) -> Result<Stats> {
+    let left_counts = counts(&lists.left_numbers);
+    let right_counts = counts(&lists.right_numbers);
+
+    let mut contributions = Vec::new();
+    let mut r = 0;
+    for &(id, left_count) in left_counts.iter() {
+        while r < right_counts.len() && right_counts[r].0 < id {
+            r += 1;
+        }
+        if let Some(&(_, right_count)) = right_counts.get(r).filter(|&&(right, _)| right == id) {
+            let score = i64::try_from(left_count * right_count)
+                .ok()
+                .and_then(|count| id.checked_mul(count))
+                .ok_or_else(|| Error::Overflow(format!("Contribution of id {} does not fit in 64 bits", id)))?;
+            contributions.push(Contribution { id, left_count, right_count, score });
+        }
+    }
+    let intersection = contributions.len();
+    contributions.sort_by(|a, b| b.score.cmp(&a.score).then(a.id.cmp(&b.id)));
+    contributions.truncate(top);
+
+    Ok(Stats {
+        left: list_stats(&lists.left_numbers, &left_counts, top),
+        right: list_stats(&lists.right_numbers, &right_counts, top),
+        intersection,
+        top_contributors: contributions,
+    })
+}
+
+/// Each distinct id of a sorted list with its number of occurrences.
+fn counts(sorted: &[i64]) -> Vec<(i64, usize)> {
+    sorted.chunk_by(|a, b| a == b).map(|run| (run[0], run.len())).collect()
+}
+
+fn list_stats(sorted: &[i64], counts: &[(i64, usize)], top: usize) -> ListStats {
+    let count = sorted.len();
+    let median = match count {
+        0 => None,
+        _ if count % 2 == 1 => Some(sorted[count / 2] as f64),
+        _ => Some((sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0),
+    };
+
+    let mut most_duplicated: Vec<(i64, usize)> =
+        counts.iter().copied().filter(|&(_, n)| n > 1).collect();
+    most_duplicated.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    most_duplicated.truncate(top);
+
+    ListStats {
+        count,
+        min: sorted.first().copied(),
+        max: sorted.last().copied(),
+        median,
+        distinct: counts.len(),
+        most_duplicated,
+    }
+}
+
+fn or_dash<T: fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+impl fmt::Display for ListStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  count:    {}", self.count)?;
+        writeln!(f, "  min:      {}", or_dash(self.min))?;
+        writeln!(f, "  max:      {}", or_dash(self.max))?;
+        writeln!(f, "  median:   {}", or_dash(self.median))?;
+        writeln!(f, "  distinct: {}", self.distinct)?;
+        write!(f, "  most duplicated:")?;
+        if self.most_duplicated.is_empty() {
+            write!(f, " none")?;
+        }
+        for (id, n) in self.most_duplicated.iter() {
+            write!(f, " {} (x{})", id, n)?;
+        }
+        writeln!(f)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Left list:")?;
+        write!(f, "{}", self.left)?;
+        writeln!(f, "Right list:")?;
+        write!(f, "{}", self.right)?;
+        writeln!(f, "Ids in both lists: {}", self.intersection)?;
+        writeln!(f, "Top contributors to the similarity score:")?;
+        for c in self.top_contributors.iter() {
+            writeln!(f, "  {} x{} left x{} right = {}", c.id, c.left_count, c.right_count, c.score)?;
+        }
+        Ok(())
+    }
+}
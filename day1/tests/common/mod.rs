@@ -0,0 +1,7 @@
+use aoc_common::{Diagnostics, Solution};
+use day1::{Day1, Lists};
+
+/// The puzzle's example lists.
+pub fn example() -> Lists {
+    Day1::parse(include_str!("../../examples/example.txt"), &mut Diagnostics::new(true)).unwrap()
+}
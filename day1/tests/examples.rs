@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day1::Day1;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day1>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
@@ -0,0 +1,68 @@
+use aoc_common::testing::Rng;
+use aoc_common::{Diagnostics, Error};
+use day1::external::{ExternalLists, MAX_FAN_IN};
+use day1::{parse_lists, similarity_score, total_distance};
+
+/// Pseudo-random pairs with plenty of repeated ids.
+fn generate(len: usize) -> String {
+    let mut rng = Rng::new(0x9e37_79b9_7f4a_7c15);
+    (0..len).map(|_| format!("{}   {}\n", rng.below(50), rng.below(50))).collect()
+}
+
+fn check_against_memory(input: &str, run_len: usize) {
+    let lists = parse_lists(input, &mut Diagnostics::new(true), 0).unwrap();
+    let external = ExternalLists::read(input.as_bytes(), &mut Diagnostics::new(true), 0, run_len).unwrap();
+
+    assert_eq!(external.lens(), (lists.left_numbers.len(), lists.right_numbers.len()));
+    assert_eq!(
+        external.total_distance().unwrap(),
+        total_distance(&lists.left_numbers, &lists.right_numbers).unwrap()
+    );
+    assert_eq!(
+        external.similarity_score().unwrap(),
+        similarity_score(&lists.left_numbers, &lists.right_numbers).unwrap()
+    );
+}
+
+#[test]
+fn example_matches_in_memory() {
+    let input = include_str!("../examples/example.txt");
+    check_against_memory(input, 1);
+    check_against_memory(input, 4);
+    check_against_memory(input, 1000);
+}
+
+#[test]
+fn many_runs_match_in_memory() {
+    let input = generate(5000);
+    let external = ExternalLists::read(input.as_bytes(), &mut Diagnostics::new(true), 0, 64).unwrap();
+    // 79 runs are first merged into two
+    assert_eq!(external.run_counts(), (2, 2));
+
+    check_against_memory(&input, 64);
+    check_against_memory(&input, 4999);
+}
+
+#[test]
+fn runs_beyond_the_fan_in_are_merged_in_passes() {
+    // 5000 runs of one id take two passes to get down to the fan-in
+    let input = generate(5000);
+    let external = ExternalLists::read(input.as_bytes(), &mut Diagnostics::new(true), 0, 1).unwrap();
+    let (left, right) = external.run_counts();
+    assert!(left <= MAX_FAN_IN && right <= MAX_FAN_IN);
+
+    check_against_memory(&input, 1);
+    check_against_memory(&input, 3);
+}
+
+#[test]
+fn unequal_lists_are_refused() {
+    let input = "3   4\n4   x\n2   5\n";
+    let external = ExternalLists::read(input.as_bytes(), &mut Diagnostics::new(false), 1, 2).unwrap();
+    assert_eq!(external.lens(), (3, 2));
+    assert_eq!(external.total_distance().unwrap(), 4);
+
+    let input = "1   x\n2   y\n3   3\n";
+    let external = ExternalLists::read(input.as_bytes(), &mut Diagnostics::new(false), 1, 2).unwrap();
+    assert!(matches!(external.total_distance(), Err(Error::InvalidInput(_))));
+}
@@ -0,0 +1,57 @@
+use aoc_common::testing::Rng;
+use aoc_common::Error;
+use day1::metric::{earth_movers, kendall_tau, DistanceMetric};
+
+mod common;
+use common::example;
+
+#[test]
+fn example_distances() {
+    let lists = example();
+    let distance = |metric: DistanceMetric| metric.distance(&lists).unwrap();
+
+    assert_eq!(distance(DistanceMetric::L1), 11);
+    assert_eq!(distance(DistanceMetric::L2Squared), 35);
+    assert_eq!(distance(DistanceMetric::MaxDeviation), 5);
+    assert_eq!(distance(DistanceMetric::KendallTau), 5);
+    assert_eq!(distance(DistanceMetric::EarthMovers), 11);
+}
+
+#[test]
+fn kendall_tau_matches_pairwise_count() {
+    let mut rng = Rng::new(88172645463325252);
+    let pairs: Vec<(i64, i64)> = (0..300)
+        .map(|_| {
+            let value = rng.next_u64();
+            ((value % 20) as i64, (value / 20 % 20) as i64)
+        })
+        .collect();
+
+    let mut discordant = 0;
+    for (i, a) in pairs.iter().enumerate() {
+        for b in &pairs[i + 1..] {
+            if (a.0 - b.0) * (a.1 - b.1) < 0 {
+                discordant += 1;
+            }
+        }
+    }
+    assert_eq!(kendall_tau(&pairs), discordant);
+}
+
+#[test]
+fn earth_movers_moves_surplus_mass() {
+    assert_eq!(earth_movers(&[0, 0], &[0, 10]).unwrap(), 10);
+    assert_eq!(earth_movers(&[1, 1, 5], &[2, 2, 2]).unwrap(), 5);
+    assert_eq!(earth_movers(&[], &[]).unwrap(), 0);
+}
+
+#[test]
+fn paired_metrics_refuse_unequal_lists() {
+    let mut lists = example();
+    lists.left_numbers.push(9);
+
+    for metric in [DistanceMetric::L1, DistanceMetric::L2Squared, DistanceMetric::MaxDeviation] {
+        assert!(matches!(metric.distance(&lists), Err(Error::InvalidInput(_))));
+    }
+    assert!(DistanceMetric::EarthMovers.distance(&lists).is_ok());
+}
@@ -0,0 +1,30 @@
+use aoc_common::{Diagnostics, Error, Solution};
+use day1::{similarity_score, total_distance, Day1};
+
+#[test]
+fn ids_near_i32_max_do_not_wrap() {
+    let max = i32::MAX as i64;
+    let input = format!("0 {max}\n0 {max}\n{max} {max}\n");
+    let lists = Day1::parse(&input, &mut Diagnostics::new(true)).unwrap();
+
+    assert_eq!(Day1::part1(&lists).unwrap().as_str(), (2 * max).to_string());
+    assert_eq!(Day1::part2(&lists).unwrap().as_str(), (3 * max).to_string());
+}
+
+#[test]
+fn many_duplicates_fit_in_64_bits() {
+    let max = i32::MAX as i64;
+    let ids = vec![max; 1000];
+
+    assert_eq!(similarity_score(&ids, &ids).unwrap(), 1000 * 1000 * max);
+    assert_eq!(total_distance(&ids, &ids).unwrap(), 0);
+}
+
+#[test]
+fn results_beyond_64_bits_are_refused() {
+    assert!(matches!(total_distance(&[i64::MIN], &[i64::MAX]), Err(Error::Overflow(_))));
+    assert!(matches!(total_distance(&[0, 0], &[i64::MAX, i64::MAX]), Err(Error::Overflow(_))));
+
+    let ids = [i64::MAX / 2, i64::MAX / 2, i64::MAX / 2];
+    assert!(matches!(similarity_score(&ids, &ids), Err(Error::Overflow(_))));
+}
@@ -0,0 +1,46 @@
+use aoc_common::{Diagnostics, Error, Solution};
+use day1::{parse_lists, Day1};
+
+const INPUT: &str = "3   4\n4   x\n2   5\n";
+
+#[test]
+fn half_parsed_pair_keeps_the_valid_half_and_is_reported() {
+    let mut diag = Diagnostics::new(false);
+    let lists = Day1::parse(INPUT, &mut diag).unwrap();
+
+    assert_eq!(lists.left_numbers, [2, 3, 4]);
+    assert_eq!(lists.right_numbers, [4, 5]);
+    assert_eq!(lists.pairs, [(3, 4), (2, 5)]);
+    let warning = &diag.warnings()[0];
+    assert_eq!((warning.line, warning.column, warning.text.as_str()), (2, 5, "x"));
+}
+
+#[test]
+fn unequal_lists_are_refused_without_tolerance() {
+    let mut diag = Diagnostics::new(false);
+    let lists = parse_lists(INPUT, &mut diag, 0).unwrap();
+
+    assert!(matches!(Day1::part1(&lists), Err(Error::InvalidInput(_))));
+    assert_eq!(Day1::part2(&lists).unwrap().as_str(), "4");
+}
+
+#[test]
+fn tolerance_pairs_up_unequal_lists() {
+    let mut diag = Diagnostics::new(false);
+    let lists = parse_lists(INPUT, &mut diag, 1).unwrap();
+    assert_eq!(Day1::part1(&lists).unwrap().as_str(), "4");
+
+    let lists = parse_lists("1 x\n2 y\n3 3\n", &mut diag, 1).unwrap();
+    assert!(Day1::part1(&lists).is_err());
+}
+
+#[test]
+fn single_id_lines_keep_their_column() {
+    let mut diag = Diagnostics::new(false);
+    let lists = parse_lists("3   4\n7\n    8\n2   5\n", &mut diag, 0).unwrap();
+
+    assert_eq!(lists.left_numbers, [2, 3, 7]);
+    assert_eq!(lists.right_numbers, [4, 5, 8]);
+    assert_eq!(diag.warnings().len(), 2);
+    assert_eq!(Day1::part1(&lists).unwrap().as_str(), "5");
+}
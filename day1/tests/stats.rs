@@ -0,0 +1,43 @@
+use aoc_common::{Diagnostics, Solution};
+use day1::stats::{stats, Contribution};
+use day1::{similarity_score, Day1};
+
+mod common;
+use common::example;
+
+#[test]
+fn example_stats() {
+    let stats = stats(&example(), 2).unwrap();
+
+    assert_eq!((stats.left.count, stats.left.min, stats.left.max), (6, Some(1), Some(4)));
+    assert_eq!((stats.left.median, stats.left.distinct), (Some(3.0), 4));
+    assert_eq!(stats.left.most_duplicated, [(3, 3)]);
+    assert_eq!((stats.right.median, stats.right.distinct), (Some(3.5), 4));
+    assert_eq!(stats.intersection, 2);
+    assert_eq!(
+        stats.top_contributors,
+        [
+            Contribution { id: 3, left_count: 3, right_count: 3, score: 27 },
+            Contribution { id: 4, left_count: 1, right_count: 1, score: 4 },
+        ]
+    );
+}
+
+#[test]
+fn contributions_add_up_to_similarity_score() {
+    let lists = example();
+    let stats = stats(&lists, usize::MAX).unwrap();
+
+    let total: i64 = stats.top_contributors.iter().map(|c| c.score).sum();
+    assert_eq!(total, similarity_score(&lists.left_numbers, &lists.right_numbers).unwrap());
+}
+
+#[test]
+fn empty_lists_have_no_extremes() {
+    let lists = Day1::parse("", &mut Diagnostics::new(true)).unwrap();
+    let stats = stats(&lists, 5).unwrap();
+
+    assert_eq!((stats.left.count, stats.left.min, stats.left.median), (0, None, None));
+    assert!(stats.top_contributors.is_empty());
+    assert!(stats.to_string().contains("min:      -"));
+}
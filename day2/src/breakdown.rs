@@ -0,0 +1,74 @@
+//! Aggregate view of why reports fail, for tuning the safety rules.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::policy::{SafetyPolicy, Violation};
+
+/// Unsafe reports sharing the same first violation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Category {
+    pub unsafe_reports: usize,
+    /// How many of them the dampener makes safe.
+    pub rescued: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Breakdown {
+    pub reports: usize,
+    pub safe: usize,
+    /// Unsafe reports by the first rule they break.
+    pub failures: BTreeMap<Violation, Category>,
+    /// Number of reports by their number of levels.
+    pub lengths: BTreeMap<usize, usize>,
+}
+
+/// Sort every report into a failure category, counting those the dampener
+/// rescues with at most `max_removals` removals.
+pub fn breakdown(policy: &SafetyPolicy, all_sequences: &[Vec<i32>], max_removals: usize) -> Breakdown {
+    let mut breakdown = Breakdown::default();
+    for sequence in all_sequences {
+        breakdown.reports += 1;
+        *breakdown.lengths.entry(sequence.len()).or_default() += 1;
+
+        match policy.check(sequence) {
+            Ok(()) => breakdown.safe += 1,
+            Err((_, violation)) => {
+                let category = breakdown.failures.entry(violation).or_default();
+                category.unsafe_reports += 1;
+                if policy.min_removals(sequence, max_removals).is_some() {
+                    category.rescued += 1;
+                }
+            }
+        }
+    }
+    breakdown
+}
+
+impl Breakdown {
+    /// Reports that are safe as they are or after dampening.
+    pub fn safe_with_dampener(&self) -> usize {
+        self.safe + self.failures.values().map(|category| category.rescued).sum::<usize>()
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dampened = self.safe_with_dampener();
+        writeln!(f, "Reports: {}, safe: {}, safe with dampener: {}", self.reports, self.safe, dampened)?;
+        writeln!(f)?;
+        writeln!(f, "{:<22} | {:>6} | {:>7}", "Failure", "Unsafe", "Rescued")?;
+        writeln!(f, "{}-+-{}-+-{}", "-".repeat(22), "-".repeat(6), "-".repeat(7))?;
+        for (violation, category) in self.failures.iter() {
+            let rule = violation.to_string();
+            writeln!(f, "{:<22} | {:>6} | {:>7}", rule, category.unsafe_reports, category.rescued)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{:<22} | {:>7}", "Levels", "Reports")?;
+        writeln!(f, "{}-+-{}", "-".repeat(22), "-".repeat(7))?;
+        for (len, count) in self.lengths.iter() {
+            writeln!(f, "{:<22} | {:>7}", len, count)?;
+        }
+        Ok(())
+    }
+}
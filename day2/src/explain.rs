@@ -0,0 +1,88 @@
+//! Per-report account of why a report is safe or not.
+
+use std::fmt;
+
+use aoc_common::{Error, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::policy::{SafetyPolicy, Violation};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ExplainFormat {
+    /// One line per report
+    #[default]
+    Text,
+    /// JSON array with one object per report
+    Json,
+}
+
+/// The first rule a report breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ViolationAt {
+    /// Index of the first level that cannot stand where it is.
+    pub index: usize,
+    pub rule: Violation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    /// 1-based line of the report in the input.
+    pub line: usize,
+    pub levels: Vec<i32>,
+    pub safe: bool,
+    /// `None` for safe reports.
+    pub violation: Option<ViolationAt>,
+    /// Indices of the levels the dampener removes to make an unsafe report
+    /// safe. Empty for safe reports and for reports that need more than
+    /// `max_removals` removals.
+    pub removed: Vec<usize>,
+}
+
+/// Explain the report found at 0-based input line `index`, letting the
+/// dampener remove up to `max_removals` levels.
+pub fn explain(policy: &SafetyPolicy, index: usize, sequence: &[i32], max_removals: usize) -> Explanation {
+    let violation = policy.check(sequence).err().map(|(index, rule)| ViolationAt { index, rule });
+    let removed = match violation {
+        Some(_) => policy.repair(sequence, max_removals).map(|repair| repair.removed).unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    Explanation { line: index + 1, levels: sequence.to_vec(), safe: violation.is_none(), violation, removed }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Report {}: ", self.line)?;
+        let Some(v) = self.violation else {
+            return write!(f, "safe");
+        };
+        write!(f, "unsafe, {} at index {}", v.rule, v.index)?;
+        if self.removed.is_empty() {
+            return Ok(());
+        }
+
+        let join = |values: Vec<String>| values.join(", ");
+        let indices = join(self.removed.iter().map(|i| i.to_string()).collect());
+        let levels = join(self.removed.iter().map(|&i| self.levels[i].to_string()).collect());
+        let noun = if self.removed.len() == 1 { "index" } else { "indices" };
+        write!(f, "; safe after removing {} {} ({})", noun, indices, levels)
+    }
+}
+
+/// Write the explanations to stdout in the chosen format.
+pub fn print_explanations(explanations: &[Explanation], format: ExplainFormat) -> Result<()> {
+    match format {
+        ExplainFormat::Text => {
+            for explanation in explanations {
+                println!("{}", explanation);
+            }
+        }
+        ExplainFormat::Json => {
+            let json = serde_json::to_string_pretty(explanations)
+                .map_err(|err| Error::InvalidInput(err.to_string()))?;
+            println!("{}", json);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Day 2: Red-Nosed Reports.
+//!
+//! Each line is a report of levels. A report is safe when it is strictly
+//! increasing or decreasing in steps of 1 to 3; part 2 also accepts reports
+//! that become safe after removing a single level.
+//!
+//! ```
+//! use day2::{is_valid_sequence, is_valid_with_dampener, min_removals};
+//!
+//! assert!(is_valid_sequence(&[7, 6, 4, 2, 1]));
+//! assert!(!is_valid_sequence(&[1, 3, 2, 4, 5]));
+//! assert!(is_valid_with_dampener(&[1, 3, 2, 4, 5]));
+//! assert_eq!(min_removals(&[1, 3, 2, 9, 4, 5], 2), Some(2));
+//! ```
+
+pub mod breakdown;
+pub mod explain;
+pub mod policy;
+pub mod stream;
+
+use aoc_common::{Answer, Diagnostics, ParseError, Result, Solution};
+
+use crate::policy::{Repair, SafetyPolicy};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Vec<i32>>> {
+        // Read sequences from file
+        let mut all_sequences: Vec<Vec<i32>> = Vec::new();
+        for (index, ip) in input.lines().enumerate() {
+            all_sequences.push(parse_report(index, ip, diag)?);
+        }
+        Ok(all_sequences)
+    }
+
+    fn part1(all_sequences: &Vec<Vec<i32>>) -> Result<Answer> {
+        let truehits = all_sequences
+            .iter()
+            .filter(|sequence| is_valid_sequence(sequence))
+            .count();
+        Ok(truehits.into())
+    }
+
+    fn part2(all_sequences: &Vec<Vec<i32>>) -> Result<Answer> {
+        let hits = all_sequences
+            .iter()
+            .filter(|sequence| is_valid_with_dampener(sequence))
+            .count();
+        Ok(hits.into())
+    }
+}
+
+/// The levels of the report on 0-based input line `index`. Invalid levels
+/// are reported and left out.
+pub fn parse_report(index: usize, ip: &str, diag: &mut Diagnostics) -> Result<Vec<i32>> {
+    let mut numbers: Vec<i32> = Vec::new();
+    for num in ip.split_whitespace() {
+        match num.parse::<i32>() {
+            Ok(level) => numbers.push(level),
+            Err(_) => diag.report(ParseError::new(index, ip, num, "Invalid level"))?,
+        }
+    }
+    Ok(numbers)
+}
+
+/// Whether the report is safe as is, or after removing any one level.
+pub fn is_valid_with_dampener(sequence: &[i32]) -> bool {
+    min_removals(sequence, 1).is_some()
+}
+
+/// The fewest levels that must be removed to make the report safe under the
+/// default policy, or `None` when that takes more than `max_removals`.
+pub fn min_removals(sequence: &[i32], max_removals: usize) -> Option<usize> {
+    SafetyPolicy::default().min_removals(sequence, max_removals)
+}
+
+/// The longest subsequence of the report that is safe under the default
+/// policy, with the positions removed to get it.
+pub fn longest_safe_subsequence(sequence: &[i32]) -> Option<Repair> {
+    SafetyPolicy::default().longest_safe(sequence)
+}
+
+/// Whether the report is strictly monotone with steps between 1 and 3.
+/// Reports with fewer than two levels are never valid.
+pub fn is_valid_sequence(sequence: &[i32]) -> bool {
+    SafetyPolicy::default().is_safe(sequence)
+}
@@ -1,108 +1,90 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use aoc_common::input::InputArgs;
+use aoc_common::Solution;
+use clap::Parser;
+use day2::breakdown::breakdown;
+use day2::explain::{explain, print_explanations, ExplainFormat};
+use day2::policy::PolicyArgs;
+use day2::stream::count_stream;
+use day2::Day2;
+
+/// Day 2: Red-Nosed Reports
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    /// How many levels the problem dampener may remove from a report
+    #[arg(long, default_value_t = 1)]
+    max_removals: usize,
+    #[command(flatten)]
+    policy: PolicyArgs,
+    /// Explain for every report why it is safe or not, instead of counting
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "text")]
+    explain: Option<ExplainFormat>,
+    /// Also break unsafe reports down by failure category and length
+    #[arg(long, conflicts_with = "explain")]
+    breakdown: bool,
+    /// Print the longest safe subsequence of every report, instead of counting
+    #[arg(long, conflicts_with_all = ["explain", "breakdown"])]
+    repair: bool,
+    /// Check reports while reading them instead of loading the whole input
+    #[arg(long, conflicts_with_all = ["explain", "breakdown", "repair"])]
+    stream: bool,
+    /// Number of worker threads in streaming mode
+    #[arg(long, default_value_t = 1, requires = "stream")]
+    threads: usize,
+}
 
-fn main() {
-    // Read sequences from file
-    let mut all_sequences: Vec<Vec<i32>> = Vec::new();
-    if let Ok(lines) = read_lines("data/input.txt") {
-        for line in lines {
-            if let Ok(ip) = line {
-                let numbers: Vec<i32> = ip
-                    .split_whitespace()
-                    .filter_map(|num| num.parse::<i32>().ok())
-                    .collect();
-                all_sequences.push(numbers);
-            }
-        }
+fn main() -> aoc_common::Result<()> {
+    let cli = Cli::parse();
+    let mut diag = cli.input.diagnostics();
+    let policy = cli.policy.policy()?;
+    if cli.stream {
+        let counts = count_stream(cli.input.input.open()?, &mut diag, &policy, cli.max_removals, cli.threads)?;
+        diag.print_warnings();
+
+        println!("\nNumber of valid sequences: {}", counts.truehits);
+        println!("\nNumber of valid sequences (including fixed ones): {}", counts.hits);
+        return Ok(());
     }
-    
-    let mut hits = 0;
-    let mut truehits = 0;
-    // Bruteforce
-    for (i, sequence) in all_sequences.iter().enumerate() {
-        //println!("\nAnalyzing Sequence {}: {:?}", i, sequence);
-        
-        if sequence.len() < 2 {
-            //println!("Sequence too short to analyze");
-            continue;
-        }
-
-        // Try the original sequence first
-        if is_valid_sequence(sequence) {
-            //println!("Original sequence is valid");
-            hits += 1;
-            truehits += 1;
-            continue;
-        }
-
-        // Try removing each value one at a time
-        let mut found_valid = false;
-        for skip_index in 0..sequence.len() {
-            let modified_sequence: Vec<i32> = [&sequence[..skip_index], &sequence[skip_index + 1..]].concat();
 
-            if modified_sequence.len() >= 2 && is_valid_sequence(&modified_sequence) {
-                //println!("Found valid sequence by removing index {}: {:?}", skip_index, modified_sequence);
-                hits += 1;
-                found_valid = true;
-                break;
+    let all_sequences = Day2::parse(&cli.input.read_to_string()?, &mut diag)?;
+    diag.print_warnings();
+
+    if cli.repair {
+        for (index, sequence) in all_sequences.iter().enumerate() {
+            match policy.longest_safe(sequence) {
+                Some(repair) => println!(
+                    "Report {}: {:?}, removed positions {:?}",
+                    index + 1,
+                    repair.levels,
+                    repair.removed
+                ),
+                None => println!("Report {}: no safe subsequence", index + 1),
             }
         }
-
-        if !found_valid {
-            //println!("Could not find a valid sequence by removing one number");
-        }
+        return Ok(());
     }
-    println!("\nNumber of valid sequences: {}", truehits);
-    println!("\nNumber of valid sequences (including fixed ones): {}", hits);
-    
-
-}
 
-
-
-fn is_valid_sequence(sequence: &[i32]) -> bool {
-    if sequence.len() < 2 {
-        return false;
+    if let Some(format) = cli.explain {
+        let explanations: Vec<_> = all_sequences
+            .iter()
+            .enumerate()
+            .map(|(index, sequence)| explain(&policy, index, sequence, cli.max_removals))
+            .collect();
+        return print_explanations(&explanations, format);
     }
 
-    let mut prev_value = sequence[0];
-    let mut direction_set = false;
-    let mut is_decreasing = false;
-
-    for window in sequence.windows(2) {
-        let curr_value = window[1];
-        let difference = curr_value - prev_value;
-
-        // min +1
-        if difference == 0 {
-            return false;
-        }
-
-        // range
-        if difference.abs() >= 4 || difference.abs() < 1 {
-            return false;
-        }
-
-        // direction
-        if !direction_set {
-            is_decreasing = difference < 0;
-            direction_set = true;
-        } else {
-            let current_decreasing = difference < 0;
-            if current_decreasing != is_decreasing {
-                return false;
-            }
-        }
+    let truehits = all_sequences.iter().filter(|sequence| policy.is_safe(sequence)).count();
+    println!("\nNumber of valid sequences: {}", truehits);
+    let hits = all_sequences
+        .iter()
+        .filter(|sequence| policy.min_removals(sequence, cli.max_removals).is_some())
+        .count();
+    println!("\nNumber of valid sequences (including fixed ones): {}", hits);
 
-        prev_value = curr_value;
+    if cli.breakdown {
+        println!("\n{}", breakdown(&policy, &all_sequences, cli.max_removals));
     }
 
-    true
+    Ok(())
 }
-
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
\ No newline at end of file
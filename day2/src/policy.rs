@@ -0,0 +1,365 @@
+//! The rules a report has to follow to count as safe.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{input, Error, Result};
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+/// Which way the levels of a report may move.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Directions {
+    /// Either way, but the same way throughout the report
+    #[default]
+    Monotone,
+    /// Only upwards
+    Increasing,
+    /// Only downwards
+    Decreasing,
+    /// Any way, changing as often as it likes
+    Any,
+}
+
+/// A rule of the [`SafetyPolicy`] that a report breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Violation {
+    /// Fewer than two levels.
+    TooShort,
+    /// Two neighbouring levels are equal.
+    ZeroStep,
+    /// A step is below the minimum.
+    StepTooSmall,
+    /// A step is above the maximum.
+    StepTooLarge,
+    /// A step goes against the direction set by the first one.
+    DirectionChange,
+    /// A step goes a way the policy never allows.
+    DirectionNotAllowed,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Violation::TooShort => "too short",
+            Violation::ZeroStep => "zero step",
+            Violation::StepTooSmall => "step too small",
+            Violation::StepTooLarge => "step too large",
+            Violation::DirectionChange => "direction change",
+            Violation::DirectionNotAllowed => "direction not allowed",
+        };
+        f.write_str(text)
+    }
+}
+
+/// Where a report is heading after the steps seen so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Heading {
+    /// No step has fixed the direction yet.
+    Unset,
+    Up,
+    Down,
+}
+
+const HEADINGS: [Heading; 3] = [Heading::Unset, Heading::Up, Heading::Down];
+
+/// Longest window of levels [`SafetyPolicy::min_removals`] keeps on the
+/// stack.
+const STACK_WINDOW: usize = 8;
+
+/// How a safe run reached one of its levels in [`SafetyPolicy::repair`].
+#[derive(Debug, Clone, Copy)]
+struct Link {
+    /// Levels removed before this one.
+    removed: usize,
+    /// The kept level before this one.
+    prev: usize,
+    /// Heading of the run at `prev`, or `None` when the run starts there.
+    from: Option<Heading>,
+}
+
+/// A safe subsequence of a report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    /// Positions of the kept levels, ascending.
+    pub kept: Vec<usize>,
+    /// The kept levels themselves.
+    pub levels: Vec<i32>,
+    /// Positions of the removed levels, ascending.
+    pub removed: Vec<usize>,
+}
+
+/// Step limits and direction rules for a safe report. The default policy
+/// is the puzzle's: strictly increasing or decreasing, in steps of 1 to 3.
+///
+/// Policies load from JSON, with missing fields taking their defaults:
+///
+/// ```
+/// use day2::policy::{Directions, SafetyPolicy};
+///
+/// let policy = SafetyPolicy::from_json(r#"{ "max_step": 5, "directions": "increasing" }"#).unwrap();
+/// assert_eq!((policy.min_step, policy.max_step), (1, 5));
+/// assert_eq!(policy.directions, Directions::Increasing);
+/// assert!(policy.is_safe(&[1, 6, 7]));
+/// assert!(!policy.is_safe(&[7, 6, 1]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// Smallest allowed change between neighbouring levels.
+    pub min_step: u32,
+    /// Largest allowed change between neighbouring levels.
+    pub max_step: u32,
+    pub directions: Directions,
+    /// Whether neighbouring levels may be equal. Such steps are exempt from
+    /// `min_step` and do not set the direction of the report.
+    pub allow_equal: bool,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> SafetyPolicy {
+        SafetyPolicy { min_step: 1, max_step: 3, directions: Directions::Monotone, allow_equal: false }
+    }
+}
+
+impl SafetyPolicy {
+    /// Parse a policy from JSON and check that it is consistent.
+    pub fn from_json(json: &str) -> Result<SafetyPolicy> {
+        let policy: SafetyPolicy = serde_json::from_str(json)
+            .map_err(|err| Error::InvalidInput(format!("Invalid safety policy: {}", err)))?;
+        policy.validate()
+    }
+
+    /// Read a policy from a JSON config file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<SafetyPolicy> {
+        let path = path.as_ref();
+        SafetyPolicy::from_json(&input::read_to_string(path)?).map_err(|err| match err {
+            Error::InvalidInput(msg) => Error::InvalidInput(format!("{}: {}", path.display(), msg)),
+            err => err,
+        })
+    }
+
+    fn validate(self) -> Result<SafetyPolicy> {
+        if self.min_step > self.max_step {
+            return Err(Error::InvalidInput(format!(
+                "Invalid safety policy: min step {} is above max step {}",
+                self.min_step, self.max_step
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Whether the report follows the policy. Reports with fewer than two
+    /// levels are never safe.
+    pub fn is_safe(&self, sequence: &[i32]) -> bool {
+        self.check(sequence).is_ok()
+    }
+
+    /// The first rule the report breaks, with the index of the level that
+    /// breaks it. A report that is too short fails at its end.
+    pub fn check(&self, sequence: &[i32]) -> std::result::Result<(), (usize, Violation)> {
+        self.check_levels(sequence.iter().copied())
+    }
+
+    /// [`check`](SafetyPolicy::check) over any run of levels.
+    pub(crate) fn check_levels<I>(&self, levels: I) -> std::result::Result<(), (usize, Violation)>
+    where
+        I: IntoIterator<Item = i32>,
+    {
+        let mut levels = levels.into_iter();
+        let Some(mut prev) = levels.next() else {
+            return Err((0, Violation::TooShort));
+        };
+
+        let mut heading = Heading::Unset;
+        let mut len = 1;
+        for curr in levels {
+            heading = self.step(heading, prev, curr).map_err(|violation| (len, violation))?;
+            prev = curr;
+            len += 1;
+        }
+
+        if len < 2 {
+            return Err((len, Violation::TooShort));
+        }
+        Ok(())
+    }
+
+    /// The fewest levels that must be removed to make the report safe, or
+    /// `None` when that takes more than `max_removals`.
+    ///
+    /// This is the dynamic program of [`repair`](SafetyPolicy::repair)
+    /// without the links back, so only the counts for the last
+    /// `max_removals + 1` levels are kept. Small budgets need no allocation.
+    pub fn min_removals(&self, sequence: &[i32], max_removals: usize) -> Option<usize> {
+        let window = max_removals.min(sequence.len()) + 1;
+        if window <= STACK_WINDOW {
+            self.count_removals(sequence, max_removals, &mut [[None; 3]; STACK_WINDOW][..window])
+        } else {
+            self.count_removals(sequence, max_removals, &mut vec![[None; 3]; window])
+        }
+    }
+
+    /// [`min_removals`](SafetyPolicy::min_removals) with the fewest removals
+    /// before the run ending at level `i` and heading `HEADINGS[h]` kept in
+    /// `best[i % best.len()][h]`.
+    fn count_removals(&self, sequence: &[i32], max_removals: usize, best: &mut [[Option<usize>; 3]]) -> Option<usize> {
+        let n = sequence.len();
+        let window = best.len();
+
+        for i in 1..n {
+            let mut ending = [None; 3];
+            for skipped in 0..=max_removals.min(i - 1) {
+                let prev = i - 1 - skipped;
+                let starts = Some((prev, Heading::Unset));
+                let extends = HEADINGS.map(|heading| best[prev % window][heading as usize].map(|removed| (removed, heading)));
+                for (removed, heading) in [starts].into_iter().chain(extends).flatten() {
+                    if let Ok(next) = self.step(heading, sequence[prev], sequence[i]) {
+                        let removed = removed + skipped;
+                        let slot = &mut ending[next as usize];
+                        if slot.is_none_or(|fewest| removed < fewest) {
+                            *slot = Some(removed);
+                        }
+                    }
+                }
+            }
+            // Written once every earlier level of the window has been read
+            best[i % window] = ending;
+        }
+
+        // Runs ending before the window drop more than `max_removals` levels
+        (n.saturating_sub(window)..n)
+            .flat_map(|i| best[i % window].map(|removed| removed.map(|removed| removed + n - 1 - i)))
+            .flatten()
+            .min()
+            .filter(|&removed| removed <= max_removals)
+    }
+
+    /// The longest safe subsequence of the report, or `None` when no two of
+    /// its levels make a safe report.
+    pub fn longest_safe(&self, sequence: &[i32]) -> Option<Repair> {
+        self.repair(sequence, sequence.len())
+    }
+
+    /// A safe subsequence of the report with as few levels removed as
+    /// possible, or `None` when that takes more than `max_removals`.
+    ///
+    /// A kept level can only follow one of the `max_removals + 1` levels
+    /// before it, so the dynamic program below runs in O(n·k) without
+    /// copying the report.
+    pub fn repair(&self, sequence: &[i32], max_removals: usize) -> Option<Repair> {
+        let n = sequence.len();
+        // best[i][h]: the safe run of at least two levels ending at `i` and
+        // heading `HEADINGS[h]` with the fewest removals before `i`.
+        let mut best: Vec<[Option<Link>; 3]> = vec![[None; 3]; n];
+
+        for i in 1..n {
+            for skipped in 0..=max_removals.min(i - 1) {
+                let prev = i - 1 - skipped;
+                // Either `prev` starts the run, dropping everything before
+                // it, or it extends a run ending there.
+                let starts = Some((prev, None));
+                let extends = HEADINGS
+                    .map(|heading| best[prev][heading as usize].map(|link| (link.removed, Some(heading))));
+                for (removed, from) in [starts].into_iter().chain(extends).flatten() {
+                    let heading = from.unwrap_or(Heading::Unset);
+                    if let Ok(next) = self.step(heading, sequence[prev], sequence[i]) {
+                        let removed = removed + skipped;
+                        let slot = &mut best[i][next as usize];
+                        if slot.is_none_or(|link| removed < link.removed) {
+                            *slot = Some(Link { removed, prev, from });
+                        }
+                    }
+                }
+            }
+        }
+
+        // The best run end, counting the levels dropped after it
+        let (removed, mut i, mut heading) = (0..n)
+            .flat_map(|i| HEADINGS.map(|h| best[i][h as usize].map(|link| (link.removed + n - 1 - i, i, h))))
+            .flatten()
+            .min_by_key(|&(removed, _, _)| removed)
+            .filter(|&(removed, _, _)| removed <= max_removals)?;
+
+        let mut kept = vec![i];
+        loop {
+            let link = best[i][heading as usize].expect("every run links back to its start");
+            kept.push(link.prev);
+            match link.from {
+                Some(from) => (i, heading) = (link.prev, from),
+                None => break,
+            }
+        }
+        kept.reverse();
+
+        let repair = Repair {
+            levels: kept.iter().map(|&i| sequence[i]).collect(),
+            removed: (0..n).filter(|i| kept.binary_search(i).is_err()).collect(),
+            kept,
+        };
+        debug_assert_eq!(repair.removed.len(), removed);
+        Some(repair)
+    }
+
+    /// Where the report is heading after a step from `prev` to `curr`, or
+    /// the rule the step breaks.
+    pub(crate) fn step(&self, heading: Heading, prev: i32, curr: i32) -> std::result::Result<Heading, Violation> {
+        let difference = curr as i64 - prev as i64;
+        if difference == 0 {
+            return if self.allow_equal { Ok(heading) } else { Err(Violation::ZeroStep) };
+        }
+        if difference.abs() < self.min_step as i64 {
+            return Err(Violation::StepTooSmall);
+        }
+        if difference.abs() > self.max_step as i64 {
+            return Err(Violation::StepTooLarge);
+        }
+
+        let direction = if difference > 0 { Heading::Up } else { Heading::Down };
+        match (self.directions, direction) {
+            (Directions::Any, _) => Ok(Heading::Unset),
+            (Directions::Increasing, Heading::Down) | (Directions::Decreasing, Heading::Up) => {
+                Err(Violation::DirectionNotAllowed)
+            }
+            _ if heading == Heading::Unset || heading == direction => Ok(direction),
+            _ => Err(Violation::DirectionChange),
+        }
+    }
+}
+
+/// Command line options selecting a [`SafetyPolicy`]. Flags override the
+/// values from `--policy`.
+#[derive(Args, Debug, Clone, Default)]
+pub struct PolicyArgs {
+    /// JSON file with the safety policy
+    #[arg(long)]
+    pub policy: Option<PathBuf>,
+    /// Smallest allowed change between neighbouring levels
+    #[arg(long)]
+    pub min_step: Option<u32>,
+    /// Largest allowed change between neighbouring levels
+    #[arg(long)]
+    pub max_step: Option<u32>,
+    /// Which way the levels may move
+    #[arg(long, value_enum)]
+    pub directions: Option<Directions>,
+    /// Allow neighbouring levels to be equal, or not with `--allow-equal=false`
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    pub allow_equal: Option<bool>,
+}
+
+impl PolicyArgs {
+    pub fn policy(&self) -> Result<SafetyPolicy> {
+        let mut policy = match &self.policy {
+            Some(path) => SafetyPolicy::from_file(path)?,
+            None => SafetyPolicy::default(),
+        };
+        policy.min_step = self.min_step.unwrap_or(policy.min_step);
+        policy.max_step = self.max_step.unwrap_or(policy.max_step);
+        policy.directions = self.directions.unwrap_or(policy.directions);
+        policy.allow_equal = self.allow_equal.unwrap_or(policy.allow_equal);
+        policy.validate()
+    }
+}
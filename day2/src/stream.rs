@@ -0,0 +1,106 @@
+//! Counting safe reports while they are read, for report logs too large to
+//! hold in memory.
+//!
+//! Reports are parsed on the calling thread, which keeps diagnostics in input
+//! order, and handed to the workers in batches over a bounded channel. At most
+//! `2 * threads` batches are in flight, so memory stays bounded however long
+//! the input is.
+
+use std::io::BufRead;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::Mutex;
+use std::thread;
+
+use aoc_common::{Diagnostics, Result};
+
+use crate::parse_report;
+use crate::policy::SafetyPolicy;
+
+/// Number of reports handed to a worker at once.
+pub const BATCH_SIZE: usize = 4096;
+
+/// The answers of both parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    /// Reports that are safe as they are.
+    pub truehits: usize,
+    /// Reports that are safe after at most `max_removals` removals.
+    pub hits: usize,
+}
+
+impl Counts {
+    fn add(&mut self, policy: &SafetyPolicy, sequence: &[i32], max_removals: usize) {
+        if policy.is_safe(sequence) {
+            self.truehits += 1;
+        }
+        if policy.min_removals(sequence, max_removals).is_some() {
+            self.hits += 1;
+        }
+    }
+}
+
+/// Count safe reports line by line, fanning batches out to `threads` workers
+/// when more than one is asked for.
+pub fn count_stream<R: BufRead>(
+    reader: R,
+    diag: &mut Diagnostics,
+    policy: &SafetyPolicy,
+    max_removals: usize,
+    threads: usize,
+) -> Result<Counts> {
+    if threads <= 1 {
+        let mut counts = Counts::default();
+        for (index, line) in reader.lines().enumerate() {
+            let sequence = parse_report(index, &line?, diag)?;
+            counts.add(policy, &sequence, max_removals);
+        }
+        return Ok(counts);
+    }
+
+    let (sender, receiver) = sync_channel::<Vec<Vec<i32>>>(2 * threads);
+    let receiver = Mutex::new(receiver);
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| scope.spawn(|| count_batches(&receiver, policy, max_removals)))
+            .collect();
+
+        // Dropping the sender on any exit, error or not, lets the workers
+        // finish before the scope joins them.
+        let read = (|| {
+            let mut batch = Vec::with_capacity(BATCH_SIZE);
+            for (index, line) in reader.lines().enumerate() {
+                batch.push(parse_report(index, &line?, diag)?);
+                if batch.len() == BATCH_SIZE {
+                    // Workers only stop once the channel is closed
+                    let _ = sender.send(std::mem::replace(&mut batch, Vec::with_capacity(BATCH_SIZE)));
+                }
+            }
+            let _ = sender.send(batch);
+            Ok(())
+        })();
+        drop(sender);
+
+        let mut counts = Counts::default();
+        for worker in workers {
+            let part = worker.join().expect("report worker panicked");
+            counts.truehits += part.truehits;
+            counts.hits += part.hits;
+        }
+        read.map(|()| counts)
+    })
+}
+
+fn count_batches(receiver: &Mutex<Receiver<Vec<Vec<i32>>>>, policy: &SafetyPolicy, max_removals: usize) -> Counts {
+    let mut counts = Counts::default();
+    loop {
+        // Hold the lock only while taking the next batch
+        let batch = receiver.lock().expect("report channel poisoned").recv();
+        let Ok(batch) = batch else {
+            return counts;
+        };
+        for sequence in batch.iter() {
+            counts.add(policy, sequence, max_removals);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::{Diagnostics, Solution};
+use day2::breakdown::{breakdown, Category};
+use day2::policy::{SafetyPolicy, Violation};
+use day2::Day2;
+
+#[test]
+fn example_breakdown() {
+    let reports = Day2::parse(include_str!("../examples/example.txt"), &mut Diagnostics::new(true)).unwrap();
+    let breakdown = breakdown(&SafetyPolicy::default(), &reports, 1);
+
+    assert_eq!((breakdown.reports, breakdown.safe, breakdown.safe_with_dampener()), (6, 2, 4));
+    let category = |unsafe_reports, rescued| Category { unsafe_reports, rescued };
+    assert_eq!(
+        breakdown.failures.into_iter().collect::<Vec<_>>(),
+        [
+            (Violation::ZeroStep, category(1, 1)),
+            (Violation::StepTooLarge, category(2, 0)),
+            (Violation::DirectionChange, category(1, 1)),
+        ]
+    );
+    assert_eq!(breakdown.lengths.into_iter().collect::<Vec<_>>(), [(5, 6)]);
+}
+
+#[test]
+fn short_reports_have_their_own_category() {
+    let reports = vec![vec![], vec![3], vec![1, 2]];
+    let breakdown = breakdown(&SafetyPolicy::default(), &reports, 1);
+
+    assert_eq!(breakdown.failures[&Violation::TooShort], Category { unsafe_reports: 2, rescued: 0 });
+    assert_eq!(breakdown.lengths.into_iter().collect::<Vec<_>>(), [(0, 1), (1, 1), (2, 1)]);
+}
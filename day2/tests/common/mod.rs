@@ -0,0 +1,15 @@
+use day2::is_valid_sequence;
+
+/// Length of the longest safe subsequence, by trying every subset of levels.
+pub fn longest_safe_brute_force(sequence: &[i32]) -> Option<usize> {
+    (0u32..1 << sequence.len())
+        .filter(|mask| {
+            let kept: Vec<i32> = (0..sequence.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| sequence[i])
+                .collect();
+            is_valid_sequence(&kept)
+        })
+        .map(|mask| mask.count_ones() as usize)
+        .max()
+}
@@ -0,0 +1,65 @@
+use aoc_common::testing::Rng;
+use day2::policy::{Directions, SafetyPolicy};
+use day2::{is_valid_with_dampener, min_removals};
+
+mod common;
+use common::longest_safe_brute_force;
+
+fn reports() -> Vec<Vec<i32>> {
+    let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+    (0..2000)
+        .map(|_| {
+            let len = rng.below(9) as usize;
+            (0..len).map(|_| rng.below(10) as i32).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn matches_brute_force_for_every_k() {
+    for report in reports() {
+        let expected = longest_safe_brute_force(&report).map(|kept| report.len() - kept);
+        for k in 0..4 {
+            assert_eq!(
+                min_removals(&report, k),
+                expected.filter(|&removed| removed <= k),
+                "report {:?} with k = {}",
+                report,
+                k
+            );
+        }
+    }
+}
+
+#[test]
+fn one_removal_is_the_dampener() {
+    for report in reports() {
+        assert_eq!(min_removals(&report, 1).is_some(), is_valid_with_dampener(&report));
+    }
+}
+
+#[test]
+fn removals_at_either_end() {
+    assert_eq!(min_removals(&[9, 1, 2, 3], 1), Some(1));
+    assert_eq!(min_removals(&[1, 2, 3, 9, 9], 1), None);
+    assert_eq!(min_removals(&[1, 2, 3, 9, 9], 2), Some(2));
+    assert_eq!(min_removals(&[5], 3), None);
+    assert_eq!(min_removals(&[], 3), None);
+}
+
+#[test]
+fn counts_match_the_repair_for_any_budget() {
+    let policies = [
+        SafetyPolicy::default(),
+        SafetyPolicy { allow_equal: true, ..SafetyPolicy::default() },
+        SafetyPolicy { directions: Directions::Any, max_step: 5, ..SafetyPolicy::default() },
+    ];
+    for policy in policies {
+        for report in reports() {
+            for k in [0, 1, 2, 7, 8, 9, 20] {
+                let repaired = policy.repair(&report, k).map(|repair| repair.removed.len());
+                assert_eq!(policy.min_removals(&report, k), repaired, "report {:?} with k = {}", report, k);
+            }
+        }
+    }
+}
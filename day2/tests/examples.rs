@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day2::Day2;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day2>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
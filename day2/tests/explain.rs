@@ -0,0 +1,69 @@
+use day2::explain::{explain, ViolationAt};
+use day2::policy::{SafetyPolicy, Violation};
+
+fn violation(sequence: &[i32]) -> Option<ViolationAt> {
+    explain(&SafetyPolicy::default(), 0, sequence, 1).violation
+}
+
+#[test]
+fn first_violation_and_rule() {
+    let at = |index, rule| Some(ViolationAt { index, rule });
+
+    assert_eq!(violation(&[7, 6, 4, 2, 1]), None);
+    assert_eq!(violation(&[1, 2, 7, 8, 9]), at(2, Violation::StepTooLarge));
+    assert_eq!(violation(&[1, 3, 2, 4, 5]), at(2, Violation::DirectionChange));
+    assert_eq!(violation(&[8, 6, 4, 4, 1]), at(3, Violation::ZeroStep));
+    assert_eq!(violation(&[4]), at(1, Violation::TooShort));
+    assert_eq!(violation(&[]), at(0, Violation::TooShort));
+}
+
+#[test]
+fn dampened_reports_name_the_removed_level() {
+    let policy = SafetyPolicy::default();
+
+    let explanation = explain(&policy, 3, &[1, 3, 2, 4, 5], 1);
+    assert_eq!((explanation.line, explanation.safe), (4, false));
+    assert_eq!(explanation.removed, [1]);
+    assert_eq!(
+        explanation.to_string(),
+        "Report 4: unsafe, direction change at index 2; safe after removing index 1 (3)"
+    );
+
+    let explanation = explain(&policy, 0, &[9, 7, 6, 2, 1], 1);
+    assert!(explanation.removed.is_empty());
+    assert_eq!(explanation.to_string(), "Report 1: unsafe, step too large at index 3");
+
+    assert!(explain(&policy, 0, &[7, 6, 4, 2, 1], 1).removed.is_empty());
+}
+
+#[test]
+fn removals_follow_the_budget() {
+    let policy = SafetyPolicy::default();
+    let sequence = [1, 9, 2, 8, 3];
+
+    assert!(explain(&policy, 0, &sequence, 1).removed.is_empty());
+    let explanation = explain(&policy, 0, &sequence, 2);
+    assert_eq!(explanation.removed, [1, 3]);
+    assert_eq!(
+        explanation.to_string(),
+        "Report 1: unsafe, step too large at index 1; safe after removing indices 1, 3 (9, 8)"
+    );
+
+    for sequence in [[1, 3, 2, 4, 5], [9, 7, 6, 2, 1], [1, 2, 7, 8, 9], [7, 6, 4, 2, 1]] {
+        for max_removals in 0..3 {
+            let explanation = explain(&policy, 0, &sequence, max_removals);
+            let fixed = explanation.safe || !explanation.removed.is_empty();
+            assert_eq!(fixed, policy.min_removals(&sequence, max_removals).is_some(), "{:?}", sequence);
+        }
+    }
+}
+
+#[test]
+fn json_names_rules_in_snake_case() {
+    let explanation = explain(&SafetyPolicy::default(), 1, &[1, 2, 7, 8, 9], 1);
+    let json = serde_json::to_value(&explanation).unwrap();
+
+    assert_eq!(json["violation"]["rule"], "step_too_large");
+    assert_eq!(json["violation"]["index"], 2);
+    assert_eq!(json["removed"], serde_json::json!([]));
+}
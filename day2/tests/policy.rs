@@ -0,0 +1,87 @@
+use aoc_common::Error;
+use day2::policy::{Directions, PolicyArgs, SafetyPolicy};
+use day2::{is_valid_sequence, min_removals};
+
+#[test]
+fn default_policy_is_the_puzzle_rules() {
+    let policy = SafetyPolicy::default();
+    for report in [&[7, 6, 4, 2, 1][..], &[1, 2, 7, 8, 9], &[1, 3, 2, 4, 5], &[8, 6, 4, 4, 1], &[1]] {
+        assert_eq!(policy.is_safe(report), is_valid_sequence(report));
+        assert_eq!(policy.min_removals(report, 1), min_removals(report, 1));
+    }
+    assert_eq!(SafetyPolicy::from_json("{}").unwrap(), policy);
+}
+
+#[test]
+fn step_limits_and_equal_neighbours() {
+    let policy = SafetyPolicy { min_step: 2, max_step: 4, ..SafetyPolicy::default() };
+    assert!(policy.is_safe(&[1, 5, 7]));
+    assert!(!policy.is_safe(&[1, 2, 4]));
+
+    let policy = SafetyPolicy { allow_equal: true, ..SafetyPolicy::default() };
+    assert!(policy.is_safe(&[5, 5, 4, 4, 1]));
+    assert!(policy.is_safe(&[5, 5]));
+    assert!(!policy.is_safe(&[5, 5, 6, 4]));
+}
+
+#[test]
+fn allowed_directions() {
+    let with = |directions| SafetyPolicy { directions, ..SafetyPolicy::default() };
+
+    assert!(with(Directions::Any).is_safe(&[1, 3, 2, 4]));
+    assert!(!with(Directions::Monotone).is_safe(&[1, 3, 2, 4]));
+    assert!(with(Directions::Decreasing).is_safe(&[4, 3, 1]));
+    assert!(!with(Directions::Decreasing).is_safe(&[1, 3, 4]));
+    assert_eq!(with(Directions::Increasing).min_removals(&[1, 3, 2, 4], 1), Some(1));
+    assert_eq!(with(Directions::Increasing).min_removals(&[4, 3, 2, 1], 2), None);
+}
+
+#[test]
+fn invalid_policies_are_refused() {
+    assert!(matches!(SafetyPolicy::from_json(r#"{ "min_step": 4 }"#), Err(Error::InvalidInput(_))));
+    assert!(matches!(SafetyPolicy::from_json(r#"{ "max_jump": 4 }"#), Err(Error::InvalidInput(_))));
+    assert!(matches!(SafetyPolicy::from_file("no/such/policy.json"), Err(Error::Read { .. })));
+}
+
+#[test]
+fn flags_override_the_config_file() {
+    let dir = std::env::temp_dir().join(format!("day2-policy-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("policy.json");
+    std::fs::write(&path, r#"{ "max_step": 6, "directions": "any" }"#).unwrap();
+
+    let args = PolicyArgs { policy: Some(path), max_step: Some(5), ..PolicyArgs::default() };
+    let policy = args.policy().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(policy, SafetyPolicy { max_step: 5, directions: Directions::Any, ..SafetyPolicy::default() });
+}
+
+#[test]
+fn allow_equal_flag_overrides_either_way() {
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        policy: PolicyArgs,
+        input: Option<String>,
+    }
+    let allow_equal = |args: &[&str]| Cli::parse_from([&["day2"], args].concat()).policy.allow_equal;
+    assert_eq!(allow_equal(&[]), None);
+    assert_eq!(allow_equal(&["--allow-equal"]), Some(true));
+    assert_eq!(allow_equal(&["--allow-equal=false"]), Some(false));
+    assert_eq!(allow_equal(&["--allow-equal", "input.txt"]), Some(true));
+
+    let dir = std::env::temp_dir().join(format!("day2-allow-equal-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("policy.json");
+    std::fs::write(&path, r#"{ "allow_equal": true }"#).unwrap();
+
+    let with = |allow_equal| PolicyArgs { policy: Some(path.clone()), allow_equal, ..PolicyArgs::default() };
+    let policies = [with(None).policy(), with(Some(false)).policy()];
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(policies[0].as_ref().unwrap().allow_equal);
+    assert!(!policies[1].as_ref().unwrap().allow_equal);
+}
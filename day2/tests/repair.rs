@@ -0,0 +1,45 @@
+use aoc_common::testing::Rng;
+use day2::policy::{Directions, Repair, SafetyPolicy};
+use day2::{is_valid_sequence, longest_safe_subsequence};
+
+mod common;
+use common::longest_safe_brute_force;
+
+#[test]
+fn longest_safe_subsequence_matches_brute_force() {
+    let mut rng = Rng::new(0x5851_f42d_4c95_7f2d);
+    for _ in 0..2000 {
+        let len = rng.below(10) as usize;
+        let report: Vec<i32> = (0..len).map(|_| rng.below(12) as i32).collect();
+
+        let repair = longest_safe_subsequence(&report);
+        assert_eq!(repair.as_ref().map(|r| r.levels.len()), longest_safe_brute_force(&report), "{:?}", report);
+
+        if let Some(repair) = repair {
+            assert!(is_valid_sequence(&repair.levels));
+            assert_eq!(repair.kept.len() + repair.removed.len(), report.len());
+            for (&position, &level) in repair.kept.iter().zip(repair.levels.iter()) {
+                assert_eq!(report[position], level);
+            }
+        }
+    }
+}
+
+#[test]
+fn removed_positions() {
+    let repair = longest_safe_subsequence(&[1, 2, 7, 8, 9]).unwrap();
+    assert_eq!(repair, Repair { kept: vec![2, 3, 4], levels: vec![7, 8, 9], removed: vec![0, 1] });
+
+    assert_eq!(longest_safe_subsequence(&[5, 5, 5]), None);
+    assert_eq!(longest_safe_subsequence(&[5]), None);
+}
+
+#[test]
+fn repair_respects_the_policy_and_budget() {
+    let increasing = SafetyPolicy { directions: Directions::Increasing, ..SafetyPolicy::default() };
+    let repair = increasing.longest_safe(&[9, 1, 8, 2, 3]).unwrap();
+    assert_eq!((repair.levels, repair.removed), (vec![1, 2, 3], vec![0, 2]));
+
+    assert!(increasing.repair(&[9, 1, 8, 2, 3], 1).is_none());
+    assert_eq!(increasing.repair(&[9, 1, 8, 2, 3], 2).unwrap().removed, [0, 2]);
+}
@@ -0,0 +1,49 @@
+use aoc_common::{Diagnostics, Solution};
+use day2::policy::SafetyPolicy;
+use day2::stream::{count_stream, Counts, BATCH_SIZE};
+use day2::Day2;
+
+/// The example repeated past a few batches, with an invalid level and a
+/// blank line mixed in.
+fn input() -> String {
+    let example = include_str!("../examples/example.txt");
+    let mut input = String::new();
+    for i in 0..3 * BATCH_SIZE / 6 + 5 {
+        input.push_str(example);
+        if i == 100 {
+            input.push_str("1 2 x 3\n\n");
+        }
+    }
+    input
+}
+
+fn in_memory(input: &str) -> Counts {
+    let reports = Day2::parse(input, &mut Diagnostics::new(false)).unwrap();
+    Counts {
+        truehits: Day2::part1(&reports).unwrap().as_str().parse().unwrap(),
+        hits: Day2::part2(&reports).unwrap().as_str().parse().unwrap(),
+    }
+}
+
+#[test]
+fn streaming_matches_in_memory() {
+    let input = input();
+    let expected = in_memory(&input);
+
+    for threads in [1, 2, 4] {
+        let mut diag = Diagnostics::new(false);
+        let counts = count_stream(input.as_bytes(), &mut diag, &SafetyPolicy::default(), 1, threads).unwrap();
+        assert_eq!(counts, expected, "{} threads", threads);
+        assert_eq!(diag.warnings().len(), 1);
+        assert_eq!(diag.warnings()[0].line, 607);
+    }
+}
+
+#[test]
+fn strict_streaming_stops_at_the_first_error() {
+    let input = input();
+    for threads in [1, 3] {
+        let mut diag = Diagnostics::new(true);
+        assert!(count_stream(input.as_bytes(), &mut diag, &SafetyPolicy::default(), 1, threads).is_err());
+    }
+}
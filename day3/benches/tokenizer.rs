@@ -0,0 +1,102 @@
+//! Parsing generated corrupted memory: the single-pass lexer behind
+//! `day3::parse_program` against the original `match_indices` scan, which
+//! looks up the latest `do()`/`don't()` for every `mul(`.
+//!
+//! Run with `cargo bench -p day3`. The original scan is only timed on the
+//! smallest input, beyond that it takes from seconds to minutes;
+//! `cargo bench -p day3 -- --full` times it on every size.
+
+use aoc_common::bench::measure;
+use aoc_common::testing::Rng;
+use day3::interpreter::InstructionSet;
+use day3::parse_program;
+
+const SIZES: [usize; 3] = [1 << 20, 4 << 20, 16 << 20];
+
+#[derive(Clone)]
+struct Multiplication {
+    num1: i32,
+    num2: i32,
+}
+
+/// The original parser, kept as a reference.
+fn parse_multiplications_match_indices(input: &str) -> (Vec<Multiplication>, Vec<Multiplication>) {
+    let mut result = Vec::new();
+    let mut precise_result = Vec::new();
+    let controls: Vec<(usize, bool)> = input.match_indices("don't()")
+        .map(|(pos, _)| (pos, false))
+        .chain(input.match_indices("do()").map(|(pos, _)| (pos, true)))
+        .collect();
+
+    for (pos, _) in input.match_indices("mul(") {
+        if let Some(end) = input[pos..].find(')') {
+            let nums: Vec<&str> = input[pos+4..pos+end].split(',').collect();
+            if nums.len() != 2 { continue; }
+
+            let do_flag = controls.iter()
+                .filter(|(control_pos, _)| control_pos < &pos)
+                .max_by_key(|&(pos, _)| pos)
+                .is_none_or(|&(_, flag)| flag);
+
+            if let (Ok(num1), Ok(num2)) = (nums[0].trim().parse(), nums[1].trim().parse()) {
+                let mult = Multiplication { num1, num2 };
+                result.push(mult.clone());
+                if do_flag {
+                    precise_result.push(mult);
+                }
+            }
+        }
+    }
+
+    (result, precise_result)
+}
+
+/// About `len` bytes of memory with the density of instructions and noise of
+/// the puzzle input.
+fn generate(len: usize, seed: u64) -> String {
+    const NOISE: &[u8] = b"mul(do()don't)[],#!@^&*{}<>?+-~ 0123456789select:who'where%from";
+    let mut rng = Rng::new(seed);
+
+    let mut memory = String::with_capacity(len + 32);
+    while memory.len() < len {
+        match rng.below(40) {
+            0 => memory.push_str("do()"),
+            1 => memory.push_str("don't()"),
+            2..=5 => memory.push_str(&format!("mul({},{})", rng.below(1000), rng.below(1000))),
+            _ => memory.push(NOISE[rng.below(NOISE.len() as u64) as usize] as char),
+        }
+    }
+    memory
+}
+
+/// Both answers through the lexer and interpreter.
+fn sums(memory: &str) -> (i64, i64) {
+    let program = parse_program(memory);
+    (InstructionSet::part1().run(&program).unwrap(), InstructionSet::part2().run(&program).unwrap())
+}
+
+/// Both answers through the original parser.
+fn sums_match_indices(memory: &str) -> (i64, i64) {
+    let product = |m: &Multiplication| m.num1 as i64 * m.num2 as i64;
+    let (all, enabled) = parse_multiplications_match_indices(memory);
+    (all.iter().map(product).sum(), enabled.iter().map(product).sum())
+}
+
+fn main() {
+    let full = std::env::args().any(|arg| arg == "--full");
+
+    println!("{:>9} | {:>12} | {:>14}", "bytes", "lexer", "match_indices");
+    for len in SIZES {
+        let memory = generate(len, 0x9E37_79B9_7F4A_7C15);
+
+        let fast = measure(1, 10, || parse_program(&memory));
+        if len > SIZES[0] && !full {
+            println!("{:>9} | {:>12.2?} | {:>14}", len, fast.median(), "skipped");
+            continue;
+        }
+
+        assert_eq!(sums(&memory), sums_match_indices(&memory));
+        let slow = measure(0, 1, || parse_multiplications_match_indices(&memory));
+        println!("{:>9} | {:>12.2?} | {:>14.2?}", len, fast.median(), slow.median());
+    }
+}
@@ -0,0 +1,139 @@
+//! Instruction table and interpreter for corrupted memory.
+//!
+//! An [`InstructionSet`] names the instructions to look for, with their arity
+//! and what they do. The [`Lexer`](crate::lexer::Lexer) finds them in the
+//! memory and [`InstructionSet::run`] executes them, keeping a running total
+//! of the values computed while enabled.
+
+use aoc_common::{Error, Result};
+
+/// A recognised instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// `name(args)` of an instruction computing a value, like `mul(2,4)`.
+    Value { name: &'static str, args: Vec<i32> },
+    /// Count values from here on, like `do()`.
+    Enable,
+    /// Ignore values from here on, like `don't()`.
+    Disable,
+    /// Set the total back to zero.
+    Reset,
+}
+
+/// What an instruction does when it runs.
+#[derive(Debug, Clone, Copy)]
+pub enum Semantics {
+    /// Add the value computed from the operands to the total.
+    Value(fn(&[i32]) -> i64),
+    Enable,
+    Disable,
+    Reset,
+}
+
+/// An entry of the instruction table.
+#[derive(Debug, Clone, Copy)]
+pub struct Definition {
+    pub name: &'static str,
+    /// Number of operands between the parentheses.
+    pub arity: usize,
+    pub semantics: Semantics,
+}
+
+/// The instructions a program is made of.
+///
+/// ```
+/// use day3::interpreter::{InstructionSet, Semantics};
+/// use day3::lexer::Lexer;
+///
+/// let set = InstructionSet::new().register("max", 2, Semantics::Value(|args| args[0].max(args[1]) as i64));
+/// let program: Vec<_> = Lexer::new("max(3,9)mul(2,4)max(5,1)", &set).collect();
+/// assert_eq!(set.run(&program).unwrap(), 14);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InstructionSet {
+    definitions: Vec<Definition>,
+}
+
+impl InstructionSet {
+    pub fn new() -> InstructionSet {
+        InstructionSet::default()
+    }
+
+    /// Add an instruction, replacing any earlier one with the same name.
+    pub fn register(mut self, name: &'static str, arity: usize, semantics: Semantics) -> InstructionSet {
+        self.definitions.retain(|definition| definition.name != name);
+        self.definitions.push(Definition { name, arity, semantics });
+        // Longest names first, so none is shadowed by a prefix of it
+        self.definitions.sort_by_key(|definition| std::cmp::Reverse(definition.name.len()));
+        self
+    }
+
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Definition> {
+        self.definitions.iter().find(|definition| definition.name == name)
+    }
+
+    /// Part 1: every `mul(X,Y)` counts.
+    pub fn part1() -> InstructionSet {
+        InstructionSet::new().register("mul", 2, Semantics::Value(mul))
+    }
+
+    /// Part 2: `mul(X,Y)` only counts while enabled by `do()`/`don't()`.
+    pub fn part2() -> InstructionSet {
+        InstructionSet::part1()
+            .register("do", 0, Semantics::Enable)
+            .register("don't", 0, Semantics::Disable)
+    }
+
+    /// Part 2 with `add`, `sub`, `neg` and `reset()` on top.
+    pub fn extended() -> InstructionSet {
+        InstructionSet::part2()
+            .register("add", 2, Semantics::Value(|args| args[0] as i64 + args[1] as i64))
+            .register("sub", 2, Semantics::Value(|args| args[0] as i64 - args[1] as i64))
+            .register("neg", 1, Semantics::Value(|args| -(args[0] as i64)))
+            .register("reset", 0, Semantics::Reset)
+    }
+
+    /// Run the program and return the total. Instructions this set does not
+    /// define, or defines with another arity, are skipped, so a program lexed
+    /// with a different set can be run with this one.
+    pub fn run(&self, program: &[Instruction]) -> Result<i64> {
+        let mut total: i64 = 0;
+        let mut enabled = true;
+
+        for instruction in program {
+            match instruction {
+                Instruction::Value { name, args } => {
+                    let Some(&Definition { arity, semantics: Semantics::Value(eval), .. }) = self.get(name) else {
+                        continue;
+                    };
+                    if args.len() != arity {
+                        continue;
+                    }
+                    if enabled {
+                        total = total
+                            .checked_add(eval(args))
+                            .ok_or_else(|| Error::Overflow(format!("Total does not fit in 64 bits at {}", name)))?;
+                    }
+                }
+                Instruction::Enable if self.has(|s| matches!(s, Semantics::Enable)) => enabled = true,
+                Instruction::Disable if self.has(|s| matches!(s, Semantics::Disable)) => enabled = false,
+                Instruction::Reset if self.has(|s| matches!(s, Semantics::Reset)) => total = 0,
+                _ => {}
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn has(&self, semantics: impl Fn(&Semantics) -> bool) -> bool {
+        self.definitions.iter().any(|definition| semantics(&definition.semantics))
+    }
+}
+
+fn mul(args: &[i32]) -> i64 {
+    args[0] as i64 * args[1] as i64
+}
@@ -0,0 +1,133 @@
+//! Single-pass lexer over corrupted memory.
+//!
+//! The memory is walked once from left to right, looking for the names of an
+//! [`InstructionSet`] followed by `(`. Operands can only hold digits, signs,
+//! whitespace and commas, so reading them stops at the first other character;
+//! text holding a further candidate like `mul(mul(1,2)` is never read twice.
+
+use clap::ValueEnum;
+
+use crate::interpreter::{Instruction, InstructionSet, Semantics};
+
+/// How strictly instruction operands are read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Syntax {
+    /// Read operands up to the next `)`, allowing whitespace, signs and
+    /// numbers of any length
+    #[default]
+    Lenient,
+    /// Only 1 to 3 digit operands separated by commas, as in `mul(X,Y)`, with
+    /// nothing in between
+    Strict,
+}
+
+/// Iterator over the instructions of a set found in the memory, in order.
+pub struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+    set: &'a InstructionSet,
+    syntax: Syntax,
+    /// Whether some instruction name starts with this byte.
+    starts: [bool; 256],
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str, set: &'a InstructionSet) -> Lexer<'a> {
+        Lexer::with_syntax(input, set, Syntax::Lenient)
+    }
+
+    pub fn with_syntax(input: &'a str, set: &'a InstructionSet, syntax: Syntax) -> Lexer<'a> {
+        let mut starts = [false; 256];
+        for definition in set.definitions() {
+            if let Some(&first) = definition.name.as_bytes().first() {
+                starts[first as usize] = true;
+            }
+        }
+        Lexer { input, pos: 0, set, syntax, starts }
+    }
+
+    /// The `arity` operands starting at `start`, if they are valid under the
+    /// lexer's syntax. Instructions without operands must be closed at once.
+    fn operands(&self, start: usize, arity: usize) -> Option<Vec<i32>> {
+        let rest = &self.input[start..];
+        if arity == 0 {
+            return rest.starts_with(')').then(Vec::new);
+        }
+        match self.syntax {
+            Syntax::Lenient => lenient_operands(rest, arity),
+            Syntax::Strict => strict_operands(rest.as_bytes(), arity),
+        }
+    }
+}
+
+/// `arity` numbers separated by commas up to the next `)`, see the module docs.
+fn lenient_operands(rest: &str, arity: usize) -> Option<Vec<i32>> {
+    let end = rest
+        .find(|c: char| !(c.is_whitespace() || c.is_ascii_digit() || matches!(c, '+' | '-' | ',')))
+        .filter(|&end| rest[end..].starts_with(')'))?;
+
+    let nums: Vec<&str> = rest[..end].split(',').collect();
+    if nums.len() != arity {
+        return None;
+    }
+    nums.iter().map(|num| num.trim().parse().ok()).collect()
+}
+
+/// `arity` numbers of 1 to 3 digits separated by commas, then `)`.
+fn strict_operands(mut rest: &[u8], arity: usize) -> Option<Vec<i32>> {
+    let mut operands = Vec::with_capacity(arity);
+    for i in 0..arity {
+        let terminator = if i + 1 == arity { b')' } else { b',' };
+        let (operand, after) = strict_operand(rest, terminator)?;
+        operands.push(operand);
+        rest = after;
+    }
+    Some(operands)
+}
+
+/// A 1 to 3 digit number followed by `terminator`, and the bytes after it.
+fn strict_operand(bytes: &[u8], terminator: u8) -> Option<(i32, &[u8])> {
+    let digits = bytes.iter().take(4).take_while(|b| b.is_ascii_digit()).count();
+    if !(1..=3).contains(&digits) || bytes.get(digits) != Some(&terminator) {
+        return None;
+    }
+    let value = bytes[..digits].iter().fold(0, |value, &b| value * 10 + (b - b'0') as i32);
+    Some((value, &bytes[digits + 1..]))
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Instruction;
+
+    fn next(&mut self) -> Option<Instruction> {
+        let bytes = self.input.as_bytes();
+        while self.pos < bytes.len() {
+            let pos = self.pos;
+            self.pos += 1;
+            if !self.starts[bytes[pos] as usize] {
+                continue;
+            }
+
+            let rest = &bytes[pos..];
+            let Some(definition) = self.set.definitions().iter().find(|definition| {
+                let name = definition.name.as_bytes();
+                rest.starts_with(name) && rest.get(name.len()) == Some(&b'(')
+            }) else {
+                continue;
+            };
+
+            // Operands may contain further instructions, so the scan carries
+            // on right after the opening parenthesis.
+            let start = pos + definition.name.len() + 1;
+            self.pos = start;
+            if let Some(args) = self.operands(start, definition.arity) {
+                return Some(match definition.semantics {
+                    Semantics::Value(_) => Instruction::Value { name: definition.name, args },
+                    Semantics::Enable => Instruction::Enable,
+                    Semantics::Disable => Instruction::Disable,
+                    Semantics::Reset => Instruction::Reset,
+                });
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,62 @@
+//! Day 3: Mull It Over.
+//!
+//! The input is corrupted memory containing `mul(X,Y)` instructions among
+//! noise. Part 1 sums every product; part 2 only those enabled by the most
+//! recent `do()` / `don't()`. Both are [`InstructionSet`]s run by a small
+//! interpreter, which can be given further instructions.
+//!
+//! ```
+//! use day3::interpreter::InstructionSet;
+//! use day3::parse_program;
+//!
+//! let program = parse_program("mul(2,4)don't()mul(5,5)do()mul(8,5)");
+//! assert_eq!(program.len(), 5);
+//! assert_eq!(InstructionSet::part1().run(&program).unwrap(), 73);
+//! assert_eq!(InstructionSet::part2().run(&program).unwrap(), 48);
+//! ```
+
+pub mod interpreter;
+pub mod lexer;
+
+use aoc_common::{Answer, Diagnostics, Result, Solution};
+
+use crate::interpreter::{Instruction, InstructionSet};
+use crate::lexer::{Lexer, Syntax};
+
+pub struct Day3;
+
+/// The instructions of both parts found in the memory, in order.
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = Program;
+
+    // Corrupted memory is mostly noise, so there is nothing to report.
+    fn parse(input: &str, _diag: &mut Diagnostics) -> Result<Program> {
+        Ok(Program { instructions: parse_program(input) })
+    }
+
+    fn part1(program: &Program) -> Result<Answer> {
+        Ok(InstructionSet::part1().run(&program.instructions)?.into())
+    }
+
+    fn part2(program: &Program) -> Result<Answer> {
+        Ok(InstructionSet::part2().run(&program.instructions)?.into())
+    }
+}
+
+/// Scan corrupted memory for the instructions of part 2, which include those
+/// of part 1.
+pub fn parse_program(input: &str) -> Vec<Instruction> {
+    parse_program_with(input, &InstructionSet::part2(), Syntax::Lenient)
+}
+
+/// Scan corrupted memory for the instructions of `set`.
+pub fn parse_program_with(input: &str, set: &InstructionSet, syntax: Syntax) -> Vec<Instruction> {
+    Lexer::with_syntax(input, set, syntax).collect()
+}
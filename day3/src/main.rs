@@ -1,51 +1,32 @@
-use std::fs::read_to_string;
+use aoc_common::input::InputArgs;
+use clap::Parser;
+use day3::interpreter::InstructionSet;
+use day3::lexer::Syntax;
+use day3::parse_program_with;
 
-#[derive(Debug, Clone)]
-struct Multiplication {
-    num1: i32,
-    num2: i32,
+/// Day 3: Mull It Over
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+    /// How strictly instruction operands are read
+    #[arg(long, value_enum, default_value_t)]
+    syntax: Syntax,
+    /// Also run the memory with add, sub, neg and reset() understood
+    #[arg(long)]
+    extended: bool,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let content = read_to_string("data/input.txt")?;
-    let (multiplications, precise_multiplications) = parse_multiplications(&content);
-    
-    let sum: i32 = multiplications.iter().map(|m| m.num1 * m.num2).sum();
-    let precise_sum: i32 = precise_multiplications.iter().map(|m| m.num1 * m.num2).sum();
-    
-    println!("Total Sum: {}", sum);
-    println!("Precise Sum: {}", precise_sum);
-    Ok(())
-}
+fn main() -> aoc_common::Result<()> {
+    let cli = Cli::parse();
+    let input = cli.input.read_to_string()?;
+    let extended = InstructionSet::extended();
+    let program = parse_program_with(&input, &extended, cli.syntax);
 
-fn parse_multiplications(input: &str) -> (Vec<Multiplication>, Vec<Multiplication>) {
-    let mut result = Vec::new();
-    let mut precise_result = Vec::new();
-    let mut do_flag = true;
-    let controls: Vec<(usize, bool)> = input.match_indices("don't()")
-        .map(|(pos, _)| (pos, false))
-        .chain(input.match_indices("do()").map(|(pos, _)| (pos, true)))
-        .collect();
-    
-    for (pos, _) in input.match_indices("mul(") {
-        if let Some(end) = input[pos..].find(')') {
-            let nums: Vec<&str> = input[pos+4..pos+end].split(',').collect();
-            if nums.len() != 2 { continue; }
-            
-            do_flag = controls.iter()
-                .filter(|(control_pos, _)| control_pos < &pos)
-                .max_by_key(|&(pos, _)| pos)
-                .map_or(true, |&(_, flag)| flag);
-            
-            if let (Ok(num1), Ok(num2)) = (nums[0].trim().parse(), nums[1].trim().parse()) {
-                let mult = Multiplication { num1, num2 };
-                result.push(mult.clone());
-                if do_flag {
-                    precise_result.push(mult);
-                }
-            }
-        }
+    println!("Total Sum: {}", InstructionSet::part1().run(&program)?);
+    println!("Precise Sum: {}", InstructionSet::part2().run(&program)?);
+    if cli.extended {
+        println!("Extended Sum: {}", extended.run(&program)?);
     }
-    
-    (result, precise_result)
-}
\ No newline at end of file
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use day3::interpreter::InstructionSet;
+use day3::lexer::Syntax;
+use day3::parse_program_with;
+
+fn sums(input: &str, syntax: Syntax) -> (i64, i64) {
+    let program = parse_program_with(input, &InstructionSet::part2(), syntax);
+    (InstructionSet::part1().run(&program).unwrap(), InstructionSet::part2().run(&program).unwrap())
+}
+
+/// Snippet, sum of all products when lenient, and when strict.
+const CORPUS: &[(&str, i64, i64)] = &[
+    ("mul(2,4)", 8, 8),
+    ("mul(123,456)", 56088, 56088),
+    ("mul(0,7)", 0, 0),
+    ("mul(007,2)", 14, 14),
+    // Nested or overlapping candidates
+    ("mul(mul(1,2)", 2, 2),
+    ("mul(mul(3,4))", 12, 12),
+    ("mulmul(2,3)", 6, 6),
+    ("mmul(2,3)", 6, 6),
+    // Broken brackets and operators
+    ("mul(4*", 0, 0),
+    ("mul[3,7]", 0, 0),
+    ("mul(3,7]", 0, 0),
+    ("mul (2,4)", 0, 0),
+    ("MUL(2,4)", 0, 0),
+    ("mul(2,4", 0, 0),
+    ("mul(2;4)", 0, 0),
+    ("mul(6,9!", 0, 0),
+    ("?(12,34)", 0, 0),
+    // Operand count
+    ("mul(2)", 0, 0),
+    ("mul(1,2,3)", 0, 0),
+    ("mul(,3)", 0, 0),
+    ("mul(3,)", 0, 0),
+    ("mul()", 0, 0),
+    // Whitespace
+    ("mul( 2 , 3)", 6, 0),
+    ("mul(2, 3)", 6, 0),
+    ("mul(2,3 )", 6, 0),
+    ("mul(\t2,3)", 6, 0),
+    // Signs
+    ("mul(-2,3)", -6, 0),
+    ("mul(2,-3)", -6, 0),
+    ("mul(+2,3)", 6, 0),
+    ("mul(--2,3)", 0, 0),
+    // Operand length
+    ("mul(1234,5)", 6170, 0),
+    ("mul(5,1234)", 6170, 0),
+    ("mul(99999999999,1)", 0, 0),
+    // Numbers that are not plain decimal digits
+    ("mul(2.5,4)", 0, 0),
+    ("mul(0x10,2)", 0, 0),
+    ("mul(１,2)", 0, 0),
+];
+
+#[test]
+fn corpus_part1() {
+    for &(snippet, lenient, strict) in CORPUS {
+        for (syntax, expected) in [(Syntax::Lenient, lenient), (Syntax::Strict, strict)] {
+            assert_eq!(sums(snippet, syntax).0, expected, "{:?} with {:?} syntax", snippet, syntax);
+        }
+    }
+}
+
+#[test]
+fn controls_apply_in_both_modes() {
+    let input = "mul(1,2)don't()mul(3,4)do_not()mul( 5,6)do()mul(7,8)don't ()mul(9,9)";
+    for (syntax, all, enabled) in [(Syntax::Lenient, 181, 139), (Syntax::Strict, 151, 139)] {
+        assert_eq!(sums(input, syntax), (all, enabled), "{:?}", syntax);
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day3::Day3;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day3>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
@@ -0,0 +1,66 @@
+use day3::interpreter::{Instruction, InstructionSet, Semantics};
+use day3::lexer::{Lexer, Syntax};
+use day3::parse_program_with;
+
+fn run(set: &InstructionSet, input: &str) -> i64 {
+    set.run(&Lexer::new(input, set).collect::<Vec<_>>()).unwrap()
+}
+
+#[test]
+fn extended_instructions() {
+    let set = InstructionSet::extended();
+
+    assert_eq!(run(&set, "add(2,3)sub(10,4)neg(7)mul(2,2)"), 5 + 6 - 7 + 4);
+    assert_eq!(run(&set, "mul(9,9)reset()add(1,1)"), 2);
+    assert_eq!(run(&set, "add(1,1)don't()sub(0,5)neg(3)do()neg(1)"), 1);
+    // Wrong arity is noise, like a malformed mul
+    assert_eq!(run(&set, "neg(1,2)add(3)reset(1)sub()mul(2,3)"), 6);
+}
+
+#[test]
+fn part1_ignores_controls_it_does_not_define() {
+    let program = parse_program_with("mul(2,3)don't()mul(4,5)reset()", &InstructionSet::extended(), Syntax::Lenient);
+    assert_eq!(program[1], Instruction::Disable);
+
+    assert_eq!(InstructionSet::part1().run(&program).unwrap(), 26);
+    assert_eq!(InstructionSet::part2().run(&program).unwrap(), 6);
+    assert_eq!(InstructionSet::extended().run(&program).unwrap(), 0);
+}
+
+#[test]
+fn instructions_of_another_arity_are_skipped() {
+    let unary = InstructionSet::part2().register("mul", 1, Semantics::Value(|args| args[0] as i64));
+    let program: Vec<_> = Lexer::new("mul(3)mul(4)", &unary).collect();
+    assert_eq!(unary.run(&program).unwrap(), 7);
+
+    assert_eq!(InstructionSet::part1().run(&program).unwrap(), 0);
+    assert_eq!(InstructionSet::part1().run(&[Instruction::Value { name: "mul", args: vec![] }]).unwrap(), 0);
+}
+
+#[test]
+fn registering_replaces_and_adds() {
+    let set = InstructionSet::part2()
+        .register("mul", 3, Semantics::Value(|args| args.iter().map(|&a| a as i64).product()))
+        .register("sq", 1, Semantics::Value(|args| args[0] as i64 * args[0] as i64))
+        .register("muladd", 2, Semantics::Value(|args| args[0] as i64 * args[1] as i64 + 1));
+
+    assert_eq!(set.get("mul").unwrap().arity, 3);
+    assert_eq!(run(&set, "mul(2,3)mul(2,3,4)sq(5)muladd(2,3)"), 24 + 25 + 7);
+}
+
+#[test]
+fn strict_syntax_applies_to_every_arity() {
+    let set = InstructionSet::extended();
+    let strict = |input| set.run(&parse_program_with(input, &set, Syntax::Strict)).unwrap();
+
+    assert_eq!(strict("neg(12)add(1,2)sub(1000,1)add( 1,2)"), -12 + 3);
+    assert_eq!(strict("reset ()mul(2,2)reset( )"), 4);
+}
+
+#[test]
+fn overflowing_total_is_an_error() {
+    let set = InstructionSet::new().register("big", 0, Semantics::Value(|_| i64::MAX));
+    let program: Vec<_> = Lexer::new("big()big()", &set).collect();
+
+    assert!(matches!(set.run(&program), Err(aoc_common::Error::Overflow(_))));
+}
@@ -0,0 +1,34 @@
+use day3::interpreter::{Instruction, InstructionSet};
+use day3::lexer::Lexer;
+
+fn mul(num1: i32, num2: i32) -> Instruction {
+    Instruction::Value { name: "mul", args: vec![num1, num2] }
+}
+
+fn tokens(input: &str) -> Vec<Instruction> {
+    Lexer::new(input, &InstructionSet::part2()).collect()
+}
+
+#[test]
+fn example_tokens_in_order() {
+    let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    assert_eq!(
+        tokens(input),
+        [mul(2, 4), Instruction::Disable, mul(5, 5), mul(11, 8), Instruction::Enable, mul(8, 5)]
+    );
+}
+
+#[test]
+fn candidates_overlapping_a_mul_are_still_read() {
+    assert_eq!(tokens("mul(mul(1,2)"), [mul(1, 2)]);
+    assert_eq!(tokens("mul(do(),3)mul(4,5)"), [Instruction::Enable, mul(4, 5)]);
+    assert_eq!(tokens("mul(1,2"), []);
+    assert_eq!(tokens(""), []);
+}
+
+#[test]
+fn unclosed_muls_are_linear() {
+    // Every candidate looking for its own `)` would be quadratic here.
+    let input = format!("{}mul(6,7)", "mul(".repeat(200_000));
+    assert_eq!(tokens(&input), [mul(6, 7)]);
+}
@@ -0,0 +1,122 @@
+//! Day 4: Ceres Search.
+//!
+//! A word search: part 1 counts `XMAS` in all eight directions, part 2
+//! counts two `MAS` crossing diagonally in an X.
+//!
+//! ```
+//! use aoc_common::Grid;
+//! use day4::{count_cross_pattern, count_word};
+//!
+//! let grid = Grid::parse("M.S\n.A.\nM.S\n").unwrap();
+//! assert_eq!(count_cross_pattern(&grid), 1);
+//! assert_eq!(count_word(&grid, "MAS"), 2);
+//! ```
+
+use aoc_common::direction::ALL_DIRECTIONS;
+use aoc_common::{Answer, Diagnostics, Grid, Result, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Grid> {
+        Grid::parse(input).map_err(|err| diag.locate(err))
+    }
+
+    fn part1(grid: &Grid) -> Result<Answer> {
+        Ok(count_word(grid, "XMAS").into())
+    }
+
+    fn part2(grid: &Grid) -> Result<Answer> {
+        Ok(count_cross_pattern(grid).into())
+    }
+}
+
+/// Occurrences of `word` in any of the eight directions, overlaps included.
+pub fn count_word(grid: &Grid, word: &str) -> usize {
+    let word_chars: Vec<char> = word.chars().collect();
+    let mut count = 0;
+
+    for row in 0..grid.rows {
+        for col in 0..grid.cols {
+            for &(dx, dy) in ALL_DIRECTIONS.iter() {
+                if check_word_from_position(grid, row, col, &word_chars, dx, dy) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Number of `A` cells whose two diagonals both read `MAS` or `SAM`.
+pub fn count_cross_pattern(grid: &Grid) -> usize {
+    let mut count = 0;
+
+    // Pattern needs at least 3x3 space
+    for row in 1..grid.rows.saturating_sub(1) {
+        for col in 1..grid.cols.saturating_sub(1) {
+            if check_cross_at_position(grid, row, col) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn check_cross_at_position(grid: &Grid, row: usize, col: usize) -> bool {
+    let cells = &grid.cells;
+
+    // First, check center A
+    if cells[row][col] != 'A' {
+        return false;
+    }
+
+    // Get the diagonal lines
+    let forward_diagonal = [
+        cells[row-1][col+1],  // top-right
+        cells[row][col],      // center
+        cells[row+1][col-1]   // bottom-left
+    ];
+
+    let back_diagonal = [
+        cells[row-1][col-1],  // top-left
+        cells[row][col],      // center
+        cells[row+1][col+1]   // bottom-right
+    ];
+
+    // Define the patterns we're looking for
+    let pattern1 = ['M', 'A', 'S'];
+    let pattern2 = ['S', 'A', 'M'];
+
+    // Check if either diagonal matches either pattern
+    let forward_matches = forward_diagonal == pattern1 || forward_diagonal == pattern2;
+    let back_matches = back_diagonal == pattern1 || back_diagonal == pattern2;
+
+    // Both diagonals must match a pattern
+    forward_matches && back_matches
+}
+
+fn check_word_from_position(grid: &Grid, row: usize, col: usize, word: &[char], dx: i32, dy: i32) -> bool {
+    let word_len = word.len();
+    let end_row = row as i32 + dx * (word_len as i32 - 1);
+    let end_col = col as i32 + dy * (word_len as i32 - 1);
+
+    if !grid.is_valid_position(end_row, end_col) {
+        return false;
+    }
+
+    for (i, &ch) in word.iter().enumerate() {
+        let curr_row = (row as i32 + dx * i as i32) as usize;
+        let curr_col = (col as i32 + dy * i as i32) as usize;
+
+        if grid.cells[curr_row][curr_col] != ch {
+            return false;
+        }
+    }
+    true
+}
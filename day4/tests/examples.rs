@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day4::Day4;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day4>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
@@ -0,0 +1,146 @@
+//! Day 5: Print Queue.
+//!
+//! Page ordering rules `X|Y` require page X to be printed before page Y.
+//! Part 1 sums the middle pages of the updates that already follow the
+//! rules, part 2 those of the remaining updates once reordered.
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use day5::{check_sequence, process_sequences};
+//!
+//! let rules: HashSet<(i32, i32)> = [(1, 2), (2, 3), (1, 3)].into_iter().collect();
+//! assert!(check_sequence(&rules, &[1, 2, 3]));
+//! assert!(!check_sequence(&rules, &[3, 1, 2]));
+//! assert_eq!(process_sequences(&rules, &[vec![1, 2, 3], vec![3, 1, 2]]), (2, 2));
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use aoc_common::{Answer, Diagnostics, ParseError, Result, Solution};
+use log::info;
+
+pub struct Day5;
+
+/// Page ordering rules (`X|Y`) and the updates to check against them.
+pub struct Manual {
+    pub rules: HashSet<(i32, i32)>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Manual;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Manual> {
+        let mut rules: HashSet<(i32, i32)> = HashSet::with_capacity(1200);
+        let mut updates: Vec<Vec<i32>> = Vec::with_capacity(1000);
+
+        for (index, line) in input.lines().enumerate() {
+            if let Some((key_str, value_str)) = line.split_once('|') {
+                if let (Ok(key), Ok(value)) = (key_str.trim().parse(), value_str.trim().parse()) {
+                    rules.insert((key, value));
+                } else {
+                    diag.report(ParseError::new(index, line, line, "Invalid ordering rule"))?;
+                }
+            } else if !line.is_empty() {
+                let mut numbers: Vec<i32> = Vec::new();
+                for n in line.split(',').map(str::trim) {
+                    match n.parse() {
+                        Ok(page) => numbers.push(page),
+                        Err(_) => diag.report(ParseError::new(index, line, n, "Invalid page number"))?,
+                    }
+                }
+                if !numbers.is_empty() {
+                    updates.push(numbers);
+                }
+            }
+        }
+
+        info!("Input summary - Rules: {}, Sequences: {}", rules.len(), updates.len());
+        Ok(Manual { rules, updates })
+    }
+
+    fn part1(manual: &Manual) -> Result<Answer> {
+        let (valid_sum, _) = process_sequences(&manual.rules, &manual.updates);
+        Ok(valid_sum.into())
+    }
+
+    fn part2(manual: &Manual) -> Result<Answer> {
+        let (_, reordered_sum) = process_sequences(&manual.rules, &manual.updates);
+        Ok(reordered_sum.into())
+    }
+}
+
+/// Sum of the middle pages of the correctly ordered updates, and of the
+/// reordered ones.
+pub fn process_sequences(rules: &HashSet<(i32, i32)>, updates: &[Vec<i32>]) -> (i32, i32) {
+    let mut valid_sum = 0;
+    let mut reordered_sum = 0;
+    
+    for update in updates.iter() {
+        let is_valid  = check_sequence(rules, update);
+        
+        if is_valid {
+            if let Some(&middle) = update.get(update.len() / 2) {
+                valid_sum += middle;
+            }
+        } else {
+            
+            if let Some(ordered) = attempt_reordering(rules, update) {
+                if let Some(&middle) = ordered.get(ordered.len() / 2) {
+                    reordered_sum += middle;
+                }
+            }
+        }
+    }
+    
+    (valid_sum, reordered_sum)
+}
+
+/// Whether no rule requires a later page of `sequence` to come before an
+/// earlier one.
+#[inline]
+pub fn check_sequence(rules: &HashSet<(i32,i32)>, sequence: &[i32]) -> bool {
+    for i in 0..sequence.len().saturating_sub(1) {
+        for j in i + 1..sequence.len() {
+            if rules.contains(&(sequence[j], sequence[i])) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Pages that belong in the middle of `sequence` once it is ordered by the
+/// rules: those with exactly `len / 2` pages required before them.
+pub fn attempt_reordering(rules: &HashSet<(i32, i32)>, sequence: &[i32]) -> Option<Vec<i32>> {
+    let mut degrees: HashMap<i32, usize> = HashMap::new();
+    
+    // Initialize degrees for all numbers in sequence
+    for &num in sequence {
+        degrees.insert(num, 0);
+    }
+    
+    // Calculate degrees based on rules
+    for &num in sequence {
+        for &other in sequence {
+            if rules.contains(&(num, other)) {
+                *degrees.entry(other).or_insert(0) += 1;
+            }
+        }
+    }
+    
+    // Find numbers with degree equal to sequence.len() / 2
+    let target_degree = sequence.len() / 2;
+    let matching_numbers: Vec<i32> = sequence.iter()
+        .filter(|&&num| degrees.get(&num).unwrap_or(&0) == &target_degree)
+        .cloned()
+        .collect();
+    
+    if matching_numbers.is_empty() {
+        None
+    } else {
+        Some(matching_numbers)
+    }
+}
\ No newline at end of file
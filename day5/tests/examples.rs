@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day5::Day5;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day5>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
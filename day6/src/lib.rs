@@ -0,0 +1,166 @@
+//! Day 6: Guard Gallivant.
+//!
+//! A guard starts at `^` facing up and turns right at every `#`. Part 1
+//! counts the distinct cells visited before leaving the map, part 2 the
+//! positions where one extra wall would trap the guard in a loop.
+//!
+//! ```
+//! use day6::Maze;
+//!
+//! let mut maze = Maze::parse(".#.\n...\n.^.\n").unwrap();
+//! let (steps, has_loop, _path) = maze.solve().unwrap();
+//! assert_eq!((steps, has_loop), (3, false));
+//! ```
+
+use std::collections::HashSet;
+use aoc_common::{Answer, Diagnostics, Direction, Error, Grid, Result, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Maze;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Maze> {
+        Maze::parse(input).map_err(|err| diag.locate(err))
+    }
+
+    fn part1(maze: &Maze) -> Result<Answer> {
+        let mut original_maze = maze.clone();
+        let (original_steps, _, _) = original_maze.solve()?;
+        Ok(original_steps.into())
+    }
+
+    fn part2(maze: &Maze) -> Result<Answer> {
+        let mut maze = maze.clone();
+        let (_results, total_loops) = maze.try_all_wall_positions()?;
+        Ok(total_loops.into())
+    }
+}
+
+/// Signed `(row, col)`, so steps off the edge of the map are representable.
+pub type Position = (i32, i32);
+
+/// Row, column, step count and loop flag of one extra-wall experiment.
+pub type WallResult = (usize, usize, usize, bool);
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy)]
+struct WallCollision {
+    position: Position,
+    direction: Direction,
+}
+
+/// The lab map plus the state of the most recent walk.
+#[derive(Clone)]
+pub struct Maze {
+    grid: Grid,
+    steps: usize,
+    wall_collisions: HashSet<WallCollision>,
+    has_loop: bool,
+}
+
+impl Maze {
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(Maze {
+            grid: Grid::parse(input)?,
+            steps: 0,
+            wall_collisions: HashSet::new(),
+            has_loop: false,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    /// Walk the guard until it leaves the map or repeats a wall collision.
+    /// Returns the number of distinct cells visited, whether it looped, and
+    /// the path taken.
+    ///
+    /// Visited cells are marked `X` in the grid, which also overwrites the
+    /// start, so solve a fresh clone of the parsed maze each time.
+    pub fn solve(&mut self) -> Result<(usize, bool, Vec<Position>)> {
+        let start = self.grid.find('^')
+            .ok_or_else(|| Error::InvalidInput("No start position (^) found".into()))?;
+        let mut current_pos = (start.0 as i32, start.1 as i32);
+        let mut direction = Direction::Up;
+        let mut path = vec![current_pos];
+
+        self.grid.cells[start.0][start.1] = 'X';
+        self.steps = 1;
+        self.wall_collisions.clear();
+        self.has_loop = false;
+
+        loop {
+            let (dx, dy) = direction.get_delta();
+            let next_pos = (current_pos.0 + dx, current_pos.1 + dy);
+
+            let next_cell = match self.grid.get(next_pos.0, next_pos.1) {
+                Some(ch) => ch,
+                None => break,
+            };
+
+            if next_cell == '#' {
+                let collision = WallCollision {
+                    position: next_pos,
+                    direction,
+                };
+                
+                if !self.wall_collisions.insert(collision) {
+                    self.has_loop = true;
+                    break;
+                }
+                
+                direction = direction.rotate_right();
+                continue;
+            }
+
+            if next_cell != 'X' {
+                self.steps += 1;
+            }
+            current_pos = next_pos;
+            path.push(current_pos);
+            self.grid.cells[current_pos.0 as usize][current_pos.1 as usize] = 'X';
+        }
+
+        Ok((self.steps, self.has_loop, path))
+    }
+
+    /// Try one extra wall on every cell of the original path. Returns the
+    /// experiments that did not loop and the number that did.
+    pub fn try_all_wall_positions(&mut self) -> Result<(Vec<WallResult>, usize)> {
+        let mut results = Vec::new();
+        let original_grid = self.grid.clone();
+        let mut total_loops = 0;
+        
+        // Get original path
+        let (_, _, original_path) = self.solve()?;
+        self.grid = original_grid.clone();
+        
+        // Convert path to HashSet for O(1) lookups
+        let path_set: HashSet<_> = original_path.into_iter().collect();
+        
+        for row in 0..self.grid.rows {
+            for col in 0..self.grid.cols {
+                if original_grid.cells[row][col] != '#' &&
+                   original_grid.cells[row][col] != '^' &&
+                   path_set.contains(&(row as i32, col as i32)) {
+                    self.grid.cells[row][col] = '#';
+                    if let Ok((steps, has_loop, _)) = self.solve() {
+                        if has_loop {
+                            total_loops += 1;
+                            self.grid = original_grid.clone();
+                            continue;
+                        }
+                        results.push((row, col, steps, has_loop));
+                    }
+                    self.grid = original_grid.clone();
+                }
+            }
+        }
+        
+        Ok((results, total_loops))
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day6::Day6;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day6>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
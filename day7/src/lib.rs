@@ -0,0 +1,236 @@
+//! Day 7: Bridge Repair.
+//!
+//! Each equation lists a test value and numbers; operators are evaluated
+//! strictly left to right. Part 1 sums the test values reachable with `+`
+//! and `*`, part 2 also allows `||` concatenation.
+//!
+//! ```
+//! use day7::{can_make_value, evaluate, Equation};
+//!
+//! let eq = Equation { test_value: 156, numbers: vec![15, 6] };
+//! assert!(!can_make_value(&eq, false));
+//! assert!(can_make_value(&eq, true));
+//! assert_eq!(evaluate(&[6, 8, 6, 15], &['*', '|', '*']).unwrap(), 7290);
+//! assert_eq!(evaluate(&[7, 0], &['|']).unwrap(), 70);
+//! ```
+
+use aoc_common::{Answer, Diagnostics, Error, ParseError, Result, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str, diag: &mut Diagnostics) -> Result<Vec<Equation>> {
+        let mut equations = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_line(index, line) {
+                Ok(equation) => equations.push(equation),
+                Err(err) => diag.report(err)?,
+            }
+        }
+        Ok(equations)
+    }
+
+    // Part 1: Only + and *
+    fn part1(equations: &Vec<Equation>) -> Result<Answer> {
+        let result1: i64 = equations
+            .iter()
+            .filter(|eq| can_make_value(eq, false))
+            .map(|eq| eq.test_value)
+            .sum();
+        Ok(result1.into())
+    }
+
+    // Part 2: Including concatenation
+    fn part2(equations: &Vec<Equation>) -> Result<Answer> {
+        let result2: i64 = equations
+            .iter()
+            .filter(|eq| can_make_value(eq, true))
+            .map(|eq| eq.test_value)
+            .sum();
+        Ok(result2.into())
+    }
+}
+
+/// One calibration line: `test_value: numbers...`.
+#[derive(Debug)]
+pub struct Equation {
+    pub test_value: i64,
+    pub numbers: Vec<i64>,
+}
+
+/// Evaluate `numbers` left to right with `operators` between them: `+`, `*`
+/// or `|` for concatenation. Fails unless there is one operator fewer than
+/// numbers, or when the result does not fit in 64 bits.
+pub fn evaluate(numbers: &[i64], operators: &[char]) -> Result<i64> {
+    if operators.len() + 1 != numbers.len() {
+        return Err(Error::InvalidInput(format!(
+            "Expected {} operators between {} numbers, got {}",
+            numbers.len().saturating_sub(1),
+            numbers.len(),
+            operators.len()
+        )));
+    }
+    let mut result = numbers[0];
+    for i in 0..operators.len() {
+        let next = match operators[i] {
+            '+' => result.checked_add(numbers[i + 1]),
+            '*' => result.checked_mul(numbers[i + 1]),
+            '|' => concat(result, numbers[i + 1]),
+            op => return Err(Error::InvalidInput(format!("Invalid operator '{}'", op))),
+        };
+        result = next.ok_or_else(|| Error::Overflow(format!("Result does not fit in 64 bits at operator {}", i + 1)))?;
+    }
+    Ok(result)
+}
+
+/// The factor that shifts a number left to make room for `n` when
+/// concatenating: 10 to the number of digits of `n`, so 10 for 0. `None`
+/// when it does not fit in 64 bits.
+fn concat_multiplier(n: i64) -> Option<i64> {
+    let mut multiplier: i64 = 10;
+    let mut rest = n.unsigned_abs() / 10;
+    while rest > 0 {
+        multiplier = multiplier.checked_mul(10)?;
+        rest /= 10;
+    }
+    Some(multiplier)
+}
+
+/// `left || right`, or `None` on overflow.
+fn concat(left: i64, right: i64) -> Option<i64> {
+    left.checked_mul(concat_multiplier(right)?)?.checked_add(right)
+}
+
+/// Whether some choice of operators turns `eq.numbers` into
+/// `eq.test_value`, optionally allowing concatenation. An equation without
+/// numbers can make no value.
+pub fn can_make_value(eq: &Equation, include_concat: bool) -> bool {
+    if eq.numbers.is_empty() {
+        return false;
+    }
+    let ops = if include_concat {
+        vec!['+', '*', '|']
+    } else {
+        vec!['+', '*']
+    };
+    
+    let n_slots = eq.numbers.len() - 1;
+    
+    // Early exit: For single operator cases, check direct solutions first
+    if n_slots == 1 {
+        // Check concatenation
+        if include_concat && concat(eq.numbers[0], eq.numbers[1]) == Some(eq.test_value) {
+            return true;
+        }
+        
+        // Check addition
+        if eq.numbers[0].checked_add(eq.numbers[1]) == Some(eq.test_value) {
+            return true;
+        }
+        
+        // Check multiplication
+        if eq.numbers[0].checked_mul(eq.numbers[1]) == Some(eq.test_value) {
+            return true;
+        }
+        
+        // If none of the direct operations work for two numbers, return false
+        if n_slots == 1 {
+            return false;
+        }
+    }
+    
+    let total_combinations = ops.len().pow(n_slots as u32);
+    
+    // Pre-calculate concatenation multipliers for each number
+    let concat_multipliers: Option<Vec<Option<i64>>> =
+        include_concat.then(|| eq.numbers.iter().map(|&num| concat_multiplier(num)).collect());
+
+    // Try all possible combinations of operators
+    for combo in 0..total_combinations {
+        let mut operators = Vec::with_capacity(n_slots);
+        let mut temp = combo;
+        for _ in 0..n_slots {
+            operators.push(ops[temp % ops.len()]);
+            temp /= ops.len();
+        }
+        
+        let mut should_skip = false;
+        let mut current_value = eq.test_value;
+        
+        for i in (0..n_slots).rev() {
+            match operators[i] {
+                '*' if eq.numbers[i + 1] == 0 => {
+                    // Anything times zero is zero, whatever comes before it
+                    if current_value == 0 {
+                        return true;
+                    }
+                    should_skip = true;
+                    break;
+                },
+                '*' => {
+                    if current_value % eq.numbers[i + 1] != 0 {
+                        should_skip = true;
+                        break;
+                    }
+                    current_value /= eq.numbers[i + 1];
+                },
+                '|' => {
+                    // No 64-bit value ends in an operand this long
+                    let Some(divisor) = concat_multipliers.as_ref().unwrap()[i + 1] else {
+                        should_skip = true;
+                        break;
+                    };
+                    if current_value % divisor != eq.numbers[i + 1] {
+                        should_skip = true;
+                        break;
+                    }
+                    current_value /= divisor;
+                },
+                '+' => {
+                    current_value -= eq.numbers[i + 1];
+                    if current_value < 0 {
+                        should_skip = true;
+                        break;
+                    }
+                },
+                _ => unreachable!("ops only holds '+', '*' and '|'"),
+            }
+        }
+        
+        if !should_skip && current_value == eq.numbers[0] {
+            return true;
+        }
+    }
+    false
+}
+
+/// Parse a `test_value: numbers...` line; `index` is the 0-based line number
+/// used in the error location.
+pub fn parse_line(index: usize, line: &str) -> std::result::Result<Equation, ParseError> {
+    let (value_str, numbers_str) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(index, line, line, "Expected '<test value>: <numbers>'"))?;
+
+    let value_str = value_str.trim();
+    let test_value = value_str
+        .parse()
+        .map_err(|_| ParseError::new(index, line, value_str, "Invalid test value"))?;
+
+    let mut numbers: Vec<i64> = Vec::new();
+    for n in numbers_str.split_whitespace() {
+        numbers.push(n.parse().map_err(|_| ParseError::new(index, line, n, "Invalid number"))?);
+    }
+    if numbers.is_empty() {
+        return Err(ParseError::new(index, line, numbers_str, "Expected at least one number"));
+    }
+
+    Ok(Equation { test_value, numbers })
+}
@@ -0,0 +1,26 @@
+use aoc_common::Error;
+use day7::{can_make_value, evaluate, Equation};
+
+#[test]
+fn mismatched_operators_are_invalid_input() {
+    assert!(matches!(evaluate(&[], &[]), Err(Error::InvalidInput(_))));
+    assert!(matches!(evaluate(&[1, 2], &[]), Err(Error::InvalidInput(_))));
+    assert!(matches!(evaluate(&[1, 2], &['+', '*']), Err(Error::InvalidInput(_))));
+    assert_eq!(evaluate(&[4], &[]).unwrap(), 4);
+}
+
+#[test]
+fn empty_equation_makes_no_value() {
+    let eq = Equation { test_value: 0, numbers: vec![] };
+    assert!(!can_make_value(&eq, false));
+    assert!(!can_make_value(&eq, true));
+}
+
+#[test]
+fn concatenation_and_overflow() {
+    assert_eq!(evaluate(&[7, 0], &['|']).unwrap(), 70);
+    assert_eq!(evaluate(&[12, 0, 5], &['|', '|']).unwrap(), 1205);
+    assert!(matches!(evaluate(&[i64::MAX, 1], &['+']), Err(Error::Overflow(_))));
+    assert!(matches!(evaluate(&[1 << 62, 2], &['*']), Err(Error::Overflow(_))));
+    assert!(matches!(evaluate(&[1 << 60, 1], &['|']), Err(Error::Overflow(_))));
+}
@@ -0,0 +1,7 @@
+use aoc_common::testing::check_examples;
+use day7::Day7;
+
+#[test]
+fn puzzle_examples() {
+    check_examples::<Day7>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+}
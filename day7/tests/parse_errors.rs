@@ -0,0 +1,57 @@
+use aoc_common::{Diagnostics, Error, Solution};
+use day7::Day7;
+
+const INPUT: &str = "190: 10 19\n3267 81 40 27\n83: 17 x\n";
+
+#[test]
+fn malformed_lines_are_skipped_with_warnings() {
+    let mut diag = Diagnostics::new(false).with_file("input.txt");
+    let equations = Day7::parse(INPUT, &mut diag).unwrap();
+
+    assert_eq!(Day7::part1(&equations).unwrap().as_str(), "190");
+    let locations: Vec<String> = diag.warnings().iter().map(|w| w.to_string()).collect();
+    assert_eq!(
+        locations,
+        [
+            "input.txt:2:1: Expected '<test value>: <numbers>': '3267 81 40 27'",
+            "input.txt:3:8: Invalid number: 'x'",
+        ]
+    );
+}
+
+#[test]
+fn strict_mode_fails_on_first_malformed_line() {
+    let mut diag = Diagnostics::new(true);
+    match Day7::parse(INPUT, &mut diag) {
+        Err(Error::Parse(err)) => assert_eq!((err.line, err.column), (2, 1)),
+        other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn zero_operands_are_solved() {
+    let mut diag = Diagnostics::new(true);
+    let equations = Day7::parse("10: 5 0 10\n0: 7 3 0\n7: 7 0 2\n", &mut diag).unwrap();
+
+    assert_eq!(Day7::part1(&equations).unwrap().as_str(), "10");
+    assert_eq!(Day7::part2(&equations).unwrap().as_str(), "10");
+}
+
+#[test]
+fn concatenating_zero_appends_a_digit() {
+    let mut diag = Diagnostics::new(true);
+    let equations = Day7::parse("70: 7 0\n701: 7 0 1\n7: 70 0\n", &mut diag).unwrap();
+
+    assert_eq!(Day7::part1(&equations).unwrap().as_str(), "0");
+    assert_eq!(Day7::part2(&equations).unwrap().as_str(), "771");
+}
+
+#[test]
+fn huge_operands_do_not_overflow() {
+    let mut diag = Diagnostics::new(true);
+    let input = format!("5: {0} {0}\n6: {0} 2 3\n7: 1 {0} 7\n", i64::MAX);
+    let equations = Day7::parse(&input, &mut diag).unwrap();
+
+    assert_eq!(Day7::part1(&equations).unwrap().as_str(), "0");
+    assert_eq!(Day7::part2(&equations).unwrap().as_str(), "0");
+}